@@ -0,0 +1,2 @@
+RUSTFLAGS='-C link-arg=-s' cargo wasm
+cp ../../target/wasm32-unknown-unknown/release/cw721_adapter.wasm .
@@ -0,0 +1,360 @@
+use cosmwasm_std::{
+    entry_point, to_binary, Binary, Deps, DepsMut, Env, MessageInfo, QueryResponse, Response,
+    StdError, StdResult,
+};
+use cw721::{
+    AllNftInfoResponse, ApprovalResponse, ApprovalsResponse, ContractInfoResponse, Expiration,
+    NftInfoResponse, NumTokensResponse, OwnerOfResponse, TokensResponse,
+};
+
+use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::state::{load_config, save_config, Config};
+use cudos_cosmwasm::{
+    CudosMsg, CudosMsgFactory, CudosQuerier, CudosQuery, NftExtension, PaginationRequest, NFT,
+};
+
+// Same limits as cw721-base.
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 100;
+
+#[entry_point]
+pub fn instantiate(
+    deps: DepsMut<CudosQuery>,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> StdResult<Response<CudosMsg>> {
+    // Fails when the denom does not exist.
+    let querier = CudosQuerier::new(&deps.querier);
+    querier.query_denom_by_id(msg.denom_id.clone())?;
+
+    save_config(
+        deps.storage,
+        &Config {
+            denom_id: msg.denom_id.clone(),
+        },
+    )?;
+
+    Ok(Response::new().add_attribute("denom_id", msg.denom_id))
+}
+
+#[entry_point]
+pub fn execute(
+    deps: DepsMut<CudosQuery>,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response<CudosMsg>, StdError> {
+    let config = load_config(deps.storage)?;
+    let factory = CudosMsgFactory::new(&env, &info);
+
+    match msg {
+        ExecuteMsg::TransferNft {
+            recipient,
+            token_id,
+        } => execute_transfer_nft(deps, &factory, config, recipient, token_id),
+        ExecuteMsg::SendNft {
+            contract,
+            token_id,
+            msg,
+        } => execute_send_nft(deps, &factory, config, contract, token_id, msg),
+        ExecuteMsg::Approve {
+            spender,
+            token_id,
+            expires,
+        } => execute_approve(&factory, config, spender, token_id, expires),
+        ExecuteMsg::Revoke { spender, token_id } => {
+            execute_revoke(&factory, config, spender, token_id)
+        }
+        ExecuteMsg::ApproveAll { operator, expires } => {
+            execute_approve_all(&factory, operator, expires)
+        }
+        ExecuteMsg::RevokeAll { operator } => execute_revoke_all(&factory, operator),
+    }
+}
+
+pub fn execute_transfer_nft(
+    deps: DepsMut<CudosQuery>,
+    factory: &CudosMsgFactory,
+    config: Config,
+    recipient: String,
+    token_id: String,
+) -> StdResult<Response<CudosMsg>> {
+    let owner = query_nft(deps.as_ref(), &config, token_id.clone())?.owner;
+    let msg = factory.transfer_nft(config.denom_id, token_id.clone(), owner, recipient.clone());
+
+    Ok(Response::new()
+        .add_message(msg)
+        .add_attribute("action", "transfer_nft")
+        .add_attribute("sender", factory.sender())
+        .add_attribute("recipient", recipient)
+        .add_attribute("token_id", token_id))
+}
+
+pub fn execute_send_nft(
+    deps: DepsMut<CudosQuery>,
+    factory: &CudosMsgFactory,
+    config: Config,
+    contract: String,
+    token_id: String,
+    msg: Binary,
+) -> StdResult<Response<CudosMsg>> {
+    let owner = query_nft(deps.as_ref(), &config, token_id.clone())?.owner;
+    // The receiver gets a `ReceiveNft` compatible with `Cw721ReceiveMsg`, extended with the
+    // denom id.
+    let msgs = factory.send_nft(
+        config.denom_id,
+        token_id.clone(),
+        owner,
+        contract.clone(),
+        msg,
+    )?;
+
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_attribute("action", "send_nft")
+        .add_attribute("sender", factory.sender())
+        .add_attribute("recipient", contract)
+        .add_attribute("token_id", token_id))
+}
+
+pub fn execute_approve(
+    factory: &CudosMsgFactory,
+    config: Config,
+    spender: String,
+    token_id: String,
+    expires: Option<Expiration>,
+) -> StdResult<Response<CudosMsg>> {
+    check_expiration(expires)?;
+    let msg = factory.approve_nft(config.denom_id, token_id.clone(), spender.clone());
+
+    Ok(Response::new()
+        .add_message(msg)
+        .add_attribute("action", "approve")
+        .add_attribute("sender", factory.sender())
+        .add_attribute("spender", spender)
+        .add_attribute("token_id", token_id))
+}
+
+pub fn execute_revoke(
+    factory: &CudosMsgFactory,
+    config: Config,
+    spender: String,
+    token_id: String,
+) -> StdResult<Response<CudosMsg>> {
+    let msg = factory.revoke_approval(config.denom_id, token_id.clone(), spender.clone());
+
+    Ok(Response::new()
+        .add_message(msg)
+        .add_attribute("action", "revoke")
+        .add_attribute("sender", factory.sender())
+        .add_attribute("spender", spender)
+        .add_attribute("token_id", token_id))
+}
+
+pub fn execute_approve_all(
+    factory: &CudosMsgFactory,
+    operator: String,
+    expires: Option<Expiration>,
+) -> StdResult<Response<CudosMsg>> {
+    check_expiration(expires)?;
+    // Operators of the NFT module are approved for every denom of the owner, not only for the
+    // one exposed by this contract.
+    let msg = factory.approve_all(operator.clone(), true);
+
+    Ok(Response::new()
+        .add_message(msg)
+        .add_attribute("action", "approve_all")
+        .add_attribute("sender", factory.sender())
+        .add_attribute("operator", operator))
+}
+
+pub fn execute_revoke_all(
+    factory: &CudosMsgFactory,
+    operator: String,
+) -> StdResult<Response<CudosMsg>> {
+    let msg = factory.approve_all(operator.clone(), false);
+
+    Ok(Response::new()
+        .add_message(msg)
+        .add_attribute("action", "revoke_all")
+        .add_attribute("sender", factory.sender())
+        .add_attribute("operator", operator))
+}
+
+fn check_expiration(expires: Option<Expiration>) -> StdResult<()> {
+    match expires {
+        None | Some(Expiration::Never {}) => Ok(()),
+        Some(_) => Err(StdError::generic_err(
+            "approvals of the NFT module can not expire",
+        )),
+    }
+}
+
+#[entry_point]
+pub fn query(deps: Deps<CudosQuery>, _env: Env, msg: QueryMsg) -> StdResult<QueryResponse> {
+    let config = load_config(deps.storage)?;
+
+    // Approvals of the NFT module never expire, so `include_expired` has no effect.
+    match msg {
+        QueryMsg::OwnerOf { token_id, .. } => to_binary(&query_owner_of(deps, &config, token_id)?),
+        QueryMsg::Approval {
+            token_id, spender, ..
+        } => to_binary(&query_approval(deps, &config, token_id, spender)?),
+        QueryMsg::Approvals { token_id, .. } => {
+            to_binary(&query_approvals(deps, &config, token_id)?)
+        }
+        QueryMsg::NumTokens {} => to_binary(&query_num_tokens(deps, &config)?),
+        QueryMsg::ContractInfo {} => to_binary(&query_contract_info(deps, &config)?),
+        QueryMsg::NftInfo { token_id } => to_binary(&query_nft_info(deps, &config, token_id)?),
+        QueryMsg::AllNftInfo { token_id, .. } => {
+            to_binary(&query_all_nft_info(deps, &config, token_id)?)
+        }
+        QueryMsg::Tokens {
+            owner,
+            start_after,
+            limit,
+        } => to_binary(&query_tokens(deps, &config, owner, start_after, limit)?),
+        QueryMsg::AllTokens { start_after, limit } => {
+            to_binary(&query_all_tokens(deps, &config, start_after, limit)?)
+        }
+    }
+}
+
+fn query_nft(deps: Deps<CudosQuery>, config: &Config, token_id: String) -> StdResult<NFT> {
+    let querier = CudosQuerier::new(&deps.querier);
+    Ok(querier.query_token(config.denom_id.clone(), token_id)?.nft)
+}
+
+pub fn query_owner_of(
+    deps: Deps<CudosQuery>,
+    config: &Config,
+    token_id: String,
+) -> StdResult<OwnerOfResponse> {
+    let nft = query_nft(deps, config, token_id)?;
+    Ok(nft.into())
+}
+
+pub fn query_approval(
+    deps: Deps<CudosQuery>,
+    config: &Config,
+    token_id: String,
+    spender: String,
+) -> StdResult<ApprovalResponse> {
+    let nft = query_nft(deps, config, token_id)?;
+    let approval = OwnerOfResponse::from(nft)
+        .approvals
+        .into_iter()
+        .find(|approval| approval.spender == spender)
+        .ok_or_else(|| StdError::not_found("Approval"))?;
+
+    Ok(ApprovalResponse { approval })
+}
+
+pub fn query_approvals(
+    deps: Deps<CudosQuery>,
+    config: &Config,
+    token_id: String,
+) -> StdResult<ApprovalsResponse> {
+    let nft = query_nft(deps, config, token_id)?;
+    Ok(ApprovalsResponse {
+        approvals: OwnerOfResponse::from(nft).approvals,
+    })
+}
+
+pub fn query_num_tokens(deps: Deps<CudosQuery>, config: &Config) -> StdResult<NumTokensResponse> {
+    let querier = CudosQuerier::new(&deps.querier);
+    let res = querier.query_supply(config.denom_id.clone())?;
+
+    Ok(res.into())
+}
+
+pub fn query_contract_info(
+    deps: Deps<CudosQuery>,
+    config: &Config,
+) -> StdResult<ContractInfoResponse> {
+    let querier = CudosQuerier::new(&deps.querier);
+    let res = querier.query_denom_by_id(config.denom_id.clone())?;
+
+    Ok(res.into())
+}
+
+pub fn query_nft_info(
+    deps: Deps<CudosQuery>,
+    config: &Config,
+    token_id: String,
+) -> StdResult<NftInfoResponse<NftExtension>> {
+    let nft = query_nft(deps, config, token_id)?;
+    Ok(nft.into())
+}
+
+pub fn query_all_nft_info(
+    deps: Deps<CudosQuery>,
+    config: &Config,
+    token_id: String,
+) -> StdResult<AllNftInfoResponse<NftExtension>> {
+    let nft = query_nft(deps, config, token_id)?;
+    Ok(nft.into())
+}
+
+pub fn query_tokens(
+    deps: Deps<CudosQuery>,
+    config: &Config,
+    owner: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<TokensResponse> {
+    let querier = CudosQuerier::new(&deps.querier);
+    let res = querier.query_owner(
+        config.denom_id.clone(),
+        owner,
+        Some(page_after(start_after, limit)),
+    )?;
+    let tokens = res
+        .iter_tokens()
+        .map(|(_, token_id)| token_id.to_string())
+        .collect();
+
+    Ok(TokensResponse { tokens })
+}
+
+pub fn query_all_tokens(
+    deps: Deps<CudosQuery>,
+    config: &Config,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<TokensResponse> {
+    let querier = CudosQuerier::new(&deps.querier);
+    let res = querier.query_collection(
+        config.denom_id.clone(),
+        Some(page_after(start_after, limit)),
+    )?;
+    let tokens = res
+        .collection
+        .and_then(|collection| collection.nfts)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|nft| nft.id)
+        .collect();
+
+    Ok(TokensResponse { tokens })
+}
+
+// The module keys the tokens of a denom by their id and returns them sorted by key, which orders
+// the ids as strings like cw721-base does. A key starts the page at the first token at or after
+// it, so the id followed by a zero byte starts it right after `start_after`.
+fn page_after(start_after: Option<String>, limit: Option<u32>) -> PaginationRequest {
+    let key = start_after.map(|token_id| {
+        let mut key = token_id.into_bytes();
+        key.push(0);
+        Binary::from(key).to_base64()
+    });
+
+    PaginationRequest {
+        key,
+        offset: None,
+        limit: Some(limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as u64),
+        count_total: None,
+        reverse: None,
+    }
+}
@@ -0,0 +1,20 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use cosmwasm_std::{StdResult, Storage};
+use cosmwasm_storage::{singleton, singleton_read};
+
+const CONFIG_KEY: &[u8] = b"config";
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Config {
+    pub denom_id: String,
+}
+
+pub fn save_config(storage: &mut dyn Storage, config: &Config) -> StdResult<()> {
+    singleton(storage, CONFIG_KEY).save(config)
+}
+
+pub fn load_config(storage: &dyn Storage) -> StdResult<Config> {
+    singleton_read(storage, CONFIG_KEY).load()
+}
@@ -0,0 +1,306 @@
+//! Runs the adapter against the emulated NFT module.
+
+use cosmwasm_std::Addr;
+use cudos_cosmwasm::testing::{ContractWrapper, CudosApp};
+use cw721::{ApprovalResponse, ApprovalsResponse, Expiration, OwnerOfResponse, TokensResponse};
+
+use cw721_adapter::contract::{execute, instantiate, query};
+use cw721_adapter::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+
+const CREATOR: &str = "creator";
+const OWNER: &str = "owner";
+const SPENDER: &str = "spender";
+
+// Mints tokens "1" to "12", the even ones to `OWNER` and the odd ones to `CREATOR`.
+fn setup() -> (CudosApp, Addr) {
+    let mut app = CudosApp::new();
+    app.nft_mut()
+        .issue_denom("kittens", "Kittens", "KIT", None, CREATOR)
+        .unwrap();
+    for i in 1..=12 {
+        let owner = if i % 2 == 0 { OWNER } else { CREATOR };
+        app.nft_mut()
+            .mint_nft("kittens", "kitten", None, None, owner)
+            .unwrap();
+    }
+
+    let code_id = app.store_code(ContractWrapper::new(execute, instantiate, query));
+    let contract = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(CREATOR),
+            &InstantiateMsg {
+                denom_id: "kittens".to_string(),
+            },
+            "kittens",
+        )
+        .unwrap();
+    (app, contract)
+}
+
+fn all_tokens(
+    app: &CudosApp,
+    contract: &Addr,
+    start_after: Option<&str>,
+    limit: u32,
+) -> Vec<String> {
+    let res: TokensResponse = app
+        .query_wasm_smart(
+            contract,
+            &QueryMsg::AllTokens {
+                start_after: start_after.map(str::to_string),
+                limit: Some(limit),
+            },
+        )
+        .unwrap();
+    res.tokens
+}
+
+fn tokens(app: &CudosApp, contract: &Addr, start_after: Option<&str>, limit: u32) -> Vec<String> {
+    let res: TokensResponse = app
+        .query_wasm_smart(
+            contract,
+            &QueryMsg::Tokens {
+                owner: OWNER.to_string(),
+                start_after: start_after.map(str::to_string),
+                limit: Some(limit),
+            },
+        )
+        .unwrap();
+    res.tokens
+}
+
+fn owner_of(app: &CudosApp, contract: &Addr, token_id: &str) -> OwnerOfResponse {
+    app.query_wasm_smart(
+        contract,
+        &QueryMsg::OwnerOf {
+            token_id: token_id.to_string(),
+            include_expired: None,
+        },
+    )
+    .unwrap()
+}
+
+#[test]
+fn all_tokens_are_paged_in_string_order() {
+    let (app, contract) = setup();
+
+    assert_eq!(
+        all_tokens(&app, &contract, None, 4),
+        ["1", "10", "11", "12"]
+    );
+    assert_eq!(
+        all_tokens(&app, &contract, Some("12"), 4),
+        ["2", "3", "4", "5"]
+    );
+    assert_eq!(
+        all_tokens(&app, &contract, Some("5"), 4),
+        ["6", "7", "8", "9"]
+    );
+    assert!(all_tokens(&app, &contract, Some("9"), 4).is_empty());
+    // `start_after` does not have to be an existing token.
+    assert_eq!(all_tokens(&app, &contract, Some("1\u{0}"), 2), ["10", "11"]);
+    assert_eq!(all_tokens(&app, &contract, Some("0"), 1), ["1"]);
+
+    // Walking the pages yields every token once.
+    let mut all = vec![];
+    let mut start_after = None;
+    loop {
+        let page = all_tokens(&app, &contract, start_after.as_deref(), 5);
+        match page.last() {
+            Some(last) => start_after = Some(last.clone()),
+            None => break,
+        }
+        all.extend(page);
+    }
+    assert_eq!(all.len(), 12);
+}
+
+#[test]
+fn tokens_of_an_owner_are_paged() {
+    let (app, contract) = setup();
+
+    assert_eq!(tokens(&app, &contract, None, 3), ["10", "12", "2"]);
+    assert_eq!(tokens(&app, &contract, Some("2"), 3), ["4", "6", "8"]);
+    // Tokens of other owners are skipped even when they follow `start_after`.
+    assert_eq!(tokens(&app, &contract, Some("1"), 1), ["10"]);
+    assert!(tokens(&app, &contract, Some("8"), 3).is_empty());
+}
+
+#[test]
+fn limit_defaults_to_ten_and_is_capped() {
+    let (app, contract) = setup();
+    let res: TokensResponse = app
+        .query_wasm_smart(
+            &contract,
+            &QueryMsg::AllTokens {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.tokens.len(), 10);
+    assert_eq!(all_tokens(&app, &contract, None, 1000).len(), 12);
+}
+
+#[test]
+fn approvals() {
+    let (mut app, contract) = setup();
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        contract.clone(),
+        &ExecuteMsg::Approve {
+            spender: SPENDER.to_string(),
+            token_id: "2".to_string(),
+            expires: Some(Expiration::Never {}),
+        },
+    )
+    .unwrap();
+
+    let res: ApprovalResponse = app
+        .query_wasm_smart(
+            &contract,
+            &QueryMsg::Approval {
+                token_id: "2".to_string(),
+                spender: SPENDER.to_string(),
+                include_expired: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.approval.spender, SPENDER);
+    assert_eq!(res.approval.expires, Expiration::Never {});
+    let res: ApprovalsResponse = app
+        .query_wasm_smart(
+            &contract,
+            &QueryMsg::Approvals {
+                token_id: "2".to_string(),
+                include_expired: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.approvals.len(), 1);
+
+    // The approved spender can transfer the token.
+    app.execute_contract(
+        Addr::unchecked(SPENDER),
+        contract.clone(),
+        &ExecuteMsg::TransferNft {
+            recipient: SPENDER.to_string(),
+            token_id: "2".to_string(),
+        },
+    )
+    .unwrap();
+    let res = owner_of(&app, &contract, "2");
+    assert_eq!(res.owner, SPENDER);
+    assert!(res.approvals.is_empty());
+
+    // A spender that is not approved can not.
+    let err = app
+        .execute_contract(
+            Addr::unchecked(SPENDER),
+            contract.clone(),
+            &ExecuteMsg::TransferNft {
+                recipient: SPENDER.to_string(),
+                token_id: "4".to_string(),
+            },
+        )
+        .unwrap_err();
+    assert!(err.to_string().ends_with("unauthorized address"));
+    assert_eq!(owner_of(&app, &contract, "4").owner, OWNER);
+}
+
+#[test]
+fn revoked_approvals_are_removed() {
+    let (mut app, contract) = setup();
+    for msg in [
+        ExecuteMsg::Approve {
+            spender: SPENDER.to_string(),
+            token_id: "2".to_string(),
+            expires: None,
+        },
+        ExecuteMsg::Revoke {
+            spender: SPENDER.to_string(),
+            token_id: "2".to_string(),
+        },
+    ] {
+        app.execute_contract(Addr::unchecked(OWNER), contract.clone(), &msg)
+            .unwrap();
+    }
+
+    assert!(owner_of(&app, &contract, "2").approvals.is_empty());
+    let err = app
+        .query_wasm_smart::<ApprovalResponse, _>(
+            &contract,
+            &QueryMsg::Approval {
+                token_id: "2".to_string(),
+                spender: SPENDER.to_string(),
+                include_expired: None,
+            },
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("Approval not found"));
+}
+
+#[test]
+fn operators_can_transfer_every_token_of_the_owner() {
+    let (mut app, contract) = setup();
+    let operator_transfer = |app: &mut CudosApp, token_id: &str| {
+        app.execute_contract(
+            Addr::unchecked(SPENDER),
+            contract.clone(),
+            &ExecuteMsg::TransferNft {
+                recipient: SPENDER.to_string(),
+                token_id: token_id.to_string(),
+            },
+        )
+    };
+
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        contract.clone(),
+        &ExecuteMsg::ApproveAll {
+            operator: SPENDER.to_string(),
+            expires: None,
+        },
+    )
+    .unwrap();
+    operator_transfer(&mut app, "2").unwrap();
+    assert_eq!(owner_of(&app, &contract, "2").owner, SPENDER);
+
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        contract.clone(),
+        &ExecuteMsg::RevokeAll {
+            operator: SPENDER.to_string(),
+        },
+    )
+    .unwrap();
+    operator_transfer(&mut app, "4").unwrap_err();
+    assert_eq!(owner_of(&app, &contract, "4").owner, OWNER);
+}
+
+#[test]
+fn expiring_approvals_are_rejected() {
+    let (mut app, contract) = setup();
+    let expires = Some(Expiration::AtHeight(100));
+
+    for msg in [
+        ExecuteMsg::Approve {
+            spender: SPENDER.to_string(),
+            token_id: "2".to_string(),
+            expires,
+        },
+        ExecuteMsg::ApproveAll {
+            operator: SPENDER.to_string(),
+            expires,
+        },
+    ] {
+        let err = app
+            .execute_contract(Addr::unchecked(OWNER), contract.clone(), &msg)
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .ends_with("approvals of the NFT module can not expire"));
+    }
+    assert!(owner_of(&app, &contract, "2").approvals.is_empty());
+}
@@ -5,12 +5,9 @@ use cosmwasm_std::{
 
 use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
 use cudos_cosmwasm::{
-    create_approve_all_msg, create_approve_nft_msg, create_burn_nft_msg, create_edit_nft_msg,
-    create_issue_denom_msg, create_mint_nft_msg, create_revoke_msg, create_transfer_nft_msg,
-    create_transfer_denom_msg,
-    CudosMsg, CudosQuerier, DenomResponse, QueryNFTResponse, DenomsResponse, CollectionResponse, SupplyResponse, 
-    OwnerCollectionResponse, QueryApprovalsResponse, QueryApprovedForAllResponse, PaginationRequest,
-    CudosQuery,
+    CudosMsg, CudosMsgFactory, CudosQuerier, DenomResponse, QueryNFTResponse, DenomsResponse,
+    CollectionResponse, SupplyResponse, OwnerCollectionResponse, QueryApprovalsResponse,
+    QueryApprovedForAllResponse, PaginationRequest, CudosQuery,
 };
 
 
@@ -31,230 +28,166 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response<CudosMsg>, StdError> {
+    let factory = CudosMsgFactory::new(&env, &info);
+
     match msg {
         ExecuteMsg::IssueDenomMsg {
             id,
             name,
             symbol,
             schema,
-        } => execute_msg_issue_denom(deps, env, info, id, name, symbol, schema),
+        } => execute_msg_issue_denom(deps, &factory, id, name, symbol, schema),
         ExecuteMsg::MintNftMsg {
             denom_id,
             name,
             uri,
             data,
             recipient,
-        } => execute_msg_mint_nft(deps, env, info, denom_id, name, uri, data, recipient),
+        } => execute_msg_mint_nft(deps, &factory, denom_id, name, uri, data, recipient),
         ExecuteMsg::EditNftMsg {
             denom_id,
             token_id,
             name,
             uri,
             data,
-        } => execute_msg_edit_nft(deps, env, info, denom_id, token_id, name, uri, data),
+        } => execute_msg_edit_nft(deps, &factory, denom_id, token_id, name, uri, data),
         ExecuteMsg::TransferNftMsg {
             denom_id,
             token_id,
             from,
             to,
-        } => execute_msg_transfer_nft(deps, env, info, denom_id, token_id, from, to),
+        } => execute_msg_transfer_nft(deps, &factory, denom_id, token_id, from, to),
         ExecuteMsg::TransferDenomMsg {
             denom_id,
             to,
-        } => execute_msg_transfer_denom(deps, env, info, denom_id, to),
+        } => execute_msg_transfer_denom(deps, &factory, denom_id, to),
         ExecuteMsg::BurnNftMsg { token_id, denom_id } => {
-            execute_msg_burn_nft(deps, env, info, denom_id, token_id)
+            execute_msg_burn_nft(deps, &factory, denom_id, token_id)
         }
         ExecuteMsg::ApproveNftMsg {
             denom_id,
             token_id,
             approved_address,
-        } => execute_msg_approve_nft(deps, env, info, denom_id, token_id, approved_address),
+        } => execute_msg_approve_nft(deps, &factory, denom_id, token_id, approved_address),
         ExecuteMsg::ApproveAllMsg {
             approved_operator,
             approved,
-        } => execute_msg_approve_all(deps, env, info, approved_operator, approved),
+        } => execute_msg_approve_all(deps, &factory, approved_operator, approved),
         ExecuteMsg::RevokeApprovalMsg {
             denom_id,
             token_id,
             address_to_revoke,
-        } => execute_msg_revoke_nft(deps, env, info, denom_id, token_id, address_to_revoke),
+        } => execute_msg_revoke_nft(deps, &factory, denom_id, token_id, address_to_revoke),
     }
 }
 
 pub fn execute_msg_issue_denom(
     _deps: DepsMut<CudosQuery>,
-    env: Env,
-    info: MessageInfo,
+    factory: &CudosMsgFactory,
     id: String,
     name: String,
     symbol: String,
     schema: Option<String>,
 ) -> StdResult<Response<CudosMsg>> {
-    let msg = create_issue_denom_msg(
-        id,
-        name,
-        symbol,
-        schema,
-        info.sender.to_string(),
-        env.contract.address.to_string(),
-    );
+    let msg = factory.issue_denom(id, name, symbol, schema);
 
     Ok(Response::new().add_message(msg))
 }
 
 pub fn execute_msg_mint_nft(
     _deps: DepsMut<CudosQuery>,
-    env: Env,
-    info: MessageInfo,
+    factory: &CudosMsgFactory,
     denom_id: String,
     name: String,
     uri: Option<String>,
     data: Option<String>,
     recipient: String,
 ) -> StdResult<Response<CudosMsg>> {
-    let msg = create_mint_nft_msg(
-        denom_id,
-        name,
-        uri,
-        data,
-        recipient,
-        info.sender.to_string(),
-        env.contract.address.to_string(),
-    );
+    let msg = factory.mint_nft(denom_id, name, uri, data, recipient);
 
     Ok(Response::new().add_message(msg))
 }
 
 pub fn execute_msg_edit_nft(
     _deps: DepsMut<CudosQuery>,
-    env: Env,
-    info: MessageInfo,
+    factory: &CudosMsgFactory,
     denom_id: String,
     token_id: String,
     name: Option<String>,
     uri: Option<String>,
     data: Option<String>,
 ) -> StdResult<Response<CudosMsg>> {
-    let msg = create_edit_nft_msg(
-        denom_id,
-        token_id,
-        name,
-        uri,
-        data,
-        info.sender.to_string(),
-        env.contract.address.to_string(),
-    );
+    let msg = factory.edit_nft(denom_id, token_id, name, uri, data);
 
     Ok(Response::new().add_message(msg))
 }
 
 pub fn execute_msg_transfer_nft(
     _deps: DepsMut<CudosQuery>,
-    env: Env,
-    info: MessageInfo,
+    factory: &CudosMsgFactory,
     denom_id: String,
     token_id: String,
     from: String,
     to: String,
 ) -> StdResult<Response<CudosMsg>> {
-    let msg = create_transfer_nft_msg(
-        denom_id,
-        token_id,
-        from,
-        to,
-        info.sender.to_string(),
-        env.contract.address.to_string(),
-    );
+    let msg = factory.transfer_nft(denom_id, token_id, from, to);
 
     Ok(Response::new().add_message(msg))
 }
 
 pub fn execute_msg_transfer_denom(
     _deps: DepsMut<CudosQuery>,
-    env: Env,
-    info: MessageInfo,
+    factory: &CudosMsgFactory,
     denom_id: String,
     to: String,
 ) -> StdResult<Response<CudosMsg>> {
-    let msg = create_transfer_denom_msg(
-        denom_id,
-        to,
-        info.sender.to_string(),
-        env.contract.address.to_string(),
-    );
+    let msg = factory.transfer_denom(denom_id, to);
 
     Ok(Response::new().add_message(msg))
 }
 
 pub fn execute_msg_burn_nft(
     _deps: DepsMut<CudosQuery>,
-    env: Env,
-    info: MessageInfo,
+    factory: &CudosMsgFactory,
     denom_id: String,
     token_id: String,
 ) -> StdResult<Response<CudosMsg>> {
-    let msg = create_burn_nft_msg(
-        denom_id,
-        token_id,
-        info.sender.to_string(),
-        env.contract.address.to_string(),
-    );
+    let msg = factory.burn_nft(denom_id, token_id);
 
     Ok(Response::new().add_message(msg))
 }
 
 pub fn execute_msg_approve_nft(
     _deps: DepsMut<CudosQuery>,
-    env: Env,
-    info: MessageInfo,
+    factory: &CudosMsgFactory,
     denom_id: String,
     token_id: String,
     approved_address: String,
 ) -> StdResult<Response<CudosMsg>> {
-    let msg = create_approve_nft_msg(
-        denom_id,
-        token_id,
-        approved_address,
-        info.sender.to_string(),
-        env.contract.address.to_string(),
-    );
+    let msg = factory.approve_nft(denom_id, token_id, approved_address);
 
     Ok(Response::new().add_message(msg))
 }
 
 pub fn execute_msg_approve_all(
     _deps: DepsMut<CudosQuery>,
-    env: Env,
-    info: MessageInfo,
+    factory: &CudosMsgFactory,
     approved_operator: String,
     approved: bool,
 ) -> StdResult<Response<CudosMsg>> {
-    let msg = create_approve_all_msg(
-        approved_operator,
-        approved,
-        info.sender.to_string(),
-        env.contract.address.to_string(),
-    );
+    let msg = factory.approve_all(approved_operator, approved);
 
     Ok(Response::new().add_message(msg))
 }
 
 pub fn execute_msg_revoke_nft(
     _deps: DepsMut<CudosQuery>,
-    env: Env,
-    info: MessageInfo,
+    factory: &CudosMsgFactory,
     denom_id: String,
     token_id: String,
     address_to_revoke: String,
 ) -> StdResult<Response<CudosMsg>> {
-    let msg = create_revoke_msg(
-        denom_id,
-        token_id,
-        address_to_revoke,
-        info.sender.to_string(),
-        env.contract.address.to_string(),
-    );
+    let msg = factory.revoke_approval(denom_id, token_id, address_to_revoke);
 
     Ok(Response::new().add_message(msg))
 }
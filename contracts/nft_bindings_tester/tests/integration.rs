@@ -0,0 +1,228 @@
+//! Runs the flow of `integration_tests/test_nft.sh` against the emulated NFT module, with the
+//! same messages and checks, so the tester can be exercised without a running node.
+
+use cosmwasm_std::{from_slice, Addr};
+use cudos_cosmwasm::testing::{ContractWrapper, CudosApp};
+use cudos_cosmwasm::{
+    CollectionResponse, CudosError, DenomResponse, DenomsResponse, OwnerCollectionResponse,
+    QueryApprovalsResponse, QueryApprovedForAllResponse, QueryNFTResponse, SupplyResponse,
+};
+use nft_bindings_tester::contract::{execute, instantiate, query};
+use nft_bindings_tester::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use serde::de::DeserializeOwned;
+
+const TESTER: &str = "wasm-tester";
+const RECEIVER: &str = "wasm-nft-receiver";
+
+macro_rules! fixture {
+    ($name:literal) => {
+        from_slice(include_bytes!(concat!(
+            "../../../integration_tests/nft_msgs/",
+            $name
+        )))
+        .unwrap()
+    };
+}
+
+fn setup() -> (CudosApp, Addr) {
+    let mut app = CudosApp::new();
+    let code_id = app.store_code(ContractWrapper::new(execute, instantiate, query));
+    let contract = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(TESTER),
+            &InstantiateMsg {},
+            "test bindings",
+        )
+        .unwrap();
+    (app, contract)
+}
+
+fn exec(app: &mut CudosApp, contract: &Addr, msg: &ExecuteMsg, sender: &str) {
+    app.execute_contract(Addr::unchecked(sender), contract.clone(), msg)
+        .unwrap();
+}
+
+fn query_fixture<T: DeserializeOwned>(app: &CudosApp, contract: &Addr, msg: QueryMsg) -> T {
+    app.query_wasm_smart(contract, &msg).unwrap()
+}
+
+fn issue_and_mint(app: &mut CudosApp, contract: &Addr) {
+    let issue_denom: ExecuteMsg = fixture!("issue_denom_msg.json");
+    exec(app, contract, &issue_denom, TESTER);
+
+    let mut mint: ExecuteMsg = fixture!("mint_nft_msg.json");
+    if let ExecuteMsg::MintNftMsg { recipient, .. } = &mut mint {
+        *recipient = TESTER.to_string();
+    }
+    exec(app, contract, &mint, TESTER);
+}
+
+#[test]
+fn issue_denom() {
+    let (mut app, contract) = setup();
+    let issue_denom: ExecuteMsg = fixture!("issue_denom_msg.json");
+    exec(&mut app, &contract, &issue_denom, TESTER);
+
+    let queries: [QueryMsg; 3] = [
+        fixture!("query_denom_by_id.json"),
+        fixture!("query_denom_by_name.json"),
+        fixture!("query_denom_by_symbol.json"),
+    ];
+    for query in &queries {
+        let res: DenomResponse = app.query_wasm_smart(&contract, query).unwrap();
+        assert_eq!(res.denom.id, "testdenom");
+        // The module records the user that executed the contract as the creator.
+        assert_eq!(res.denom.creator, TESTER);
+        assert_eq!(res.denom.schema.as_deref(), Some("testschema"));
+    }
+
+    let res: DenomsResponse = query_fixture(&app, &contract, fixture!("query_denoms.json"));
+    assert_eq!(res.denoms.unwrap()[0].id, "testdenom");
+}
+
+#[test]
+fn nft_lifecycle() {
+    let (mut app, contract) = setup();
+    issue_and_mint(&mut app, &contract);
+    let query_nft: QueryMsg = fixture!("query_nft.json");
+    let res: QueryNFTResponse = app.query_wasm_smart(&contract, &query_nft).unwrap();
+    assert_eq!(res.nft.id, "1");
+    assert_eq!(res.nft.owner, TESTER);
+
+    // EDIT NFT
+    let edit: ExecuteMsg = fixture!("edit_nft_msg.json");
+    exec(&mut app, &contract, &edit, TESTER);
+    let res: QueryNFTResponse = app.query_wasm_smart(&contract, &query_nft).unwrap();
+    assert_eq!(res.nft.name.as_deref(), Some("just_set_name"));
+    assert_eq!(res.nft.uri.as_deref(), Some("just_set_url"));
+
+    // TRANSFER NFT
+    let mut transfer: ExecuteMsg = fixture!("transfer_nft_msg.json");
+    if let ExecuteMsg::TransferNftMsg { from, to, .. } = &mut transfer {
+        *from = TESTER.to_string();
+        *to = RECEIVER.to_string();
+    }
+    exec(&mut app, &contract, &transfer, TESTER);
+    let res: QueryNFTResponse = app.query_wasm_smart(&contract, &query_nft).unwrap();
+    assert_eq!(res.nft.owner, RECEIVER);
+
+    // APPROVE NFT
+    let mut approve: ExecuteMsg = fixture!("approve_nft_msg.json");
+    if let ExecuteMsg::ApproveNftMsg {
+        approved_address, ..
+    } = &mut approve
+    {
+        *approved_address = TESTER.to_string();
+    }
+    exec(&mut app, &contract, &approve, RECEIVER);
+    let res: QueryApprovalsResponse =
+        query_fixture(&app, &contract, fixture!("query_nft_approvals.json"));
+    assert_eq!(res.approved_addresses, vec![TESTER.to_string()]);
+
+    // REVOKE APPROVE NFT
+    let mut revoke: ExecuteMsg = fixture!("revoke_approve_nft_msg.json");
+    if let ExecuteMsg::RevokeApprovalMsg {
+        address_to_revoke, ..
+    } = &mut revoke
+    {
+        *address_to_revoke = TESTER.to_string();
+    }
+    exec(&mut app, &contract, &revoke, RECEIVER);
+    let res: QueryNFTResponse = app.query_wasm_smart(&contract, &query_nft).unwrap();
+    assert_eq!(res.nft.approved_addresses, None);
+
+    // VERIFY QUERY COLLECTION AND SUPPLY
+    let res: CollectionResponse = query_fixture(&app, &contract, fixture!("query_collection.json"));
+    let nfts = res.collection.unwrap().nfts.unwrap();
+    assert_eq!(nfts[0].name.as_deref(), Some("just_set_name"));
+    let res: SupplyResponse = query_fixture(&app, &contract, fixture!("query_supply.json"));
+    assert_eq!(res.amount, 1);
+
+    // VERIFY QUERY OWNER NFTs
+    let mut query_owner: QueryMsg = fixture!("query_owner.json");
+    if let QueryMsg::QueryOwner { address, .. } = &mut query_owner {
+        *address = RECEIVER.to_string();
+    }
+    let res: OwnerCollectionResponse = app.query_wasm_smart(&contract, &query_owner).unwrap();
+    assert_eq!(res.owner.id_collections[0].token_ids, vec!["1".to_string()]);
+
+    // BURN NFT
+    let burn: ExecuteMsg = fixture!("burn_nft_msg.json");
+    exec(&mut app, &contract, &burn, RECEIVER);
+    let res: OwnerCollectionResponse = app.query_wasm_smart(&contract, &query_owner).unwrap();
+    assert!(res.owner.id_collections.is_empty());
+}
+
+#[test]
+fn approve_all() {
+    let (mut app, contract) = setup();
+    let approve_all = |approved: bool| {
+        let mut msg: ExecuteMsg = fixture!("approve_all_msg.json");
+        if let ExecuteMsg::ApproveAllMsg {
+            approved_operator,
+            approved: value,
+        } = &mut msg
+        {
+            *approved_operator = RECEIVER.to_string();
+            *value = approved;
+        }
+        msg
+    };
+    let mut query: QueryMsg = fixture!("query_approved_for_all.json");
+    if let QueryMsg::QueryApprovedForAll {
+        owner_address,
+        operator_address,
+    } = &mut query
+    {
+        *owner_address = TESTER.to_string();
+        *operator_address = RECEIVER.to_string();
+    }
+
+    exec(&mut app, &contract, &approve_all(true), TESTER);
+    let res: QueryApprovedForAllResponse = app.query_wasm_smart(&contract, &query).unwrap();
+    assert!(res.is_approved);
+
+    exec(&mut app, &contract, &approve_all(false), TESTER);
+    let res: QueryApprovedForAllResponse = app.query_wasm_smart(&contract, &query).unwrap();
+    assert!(!res.is_approved);
+}
+
+#[test]
+fn rejects_unauthorized_messages() {
+    let (mut app, contract) = setup();
+    issue_and_mint(&mut app, &contract);
+
+    // Only the creator of the denom can mint, and only the owner can burn.
+    let mint: ExecuteMsg = fixture!("mint_nft_msg.json");
+    let err = app
+        .execute_contract(Addr::unchecked(RECEIVER), contract.clone(), &mint)
+        .unwrap_err();
+    assert!(err.to_string().ends_with("unauthorized address"));
+    let burn: ExecuteMsg = fixture!("burn_nft_msg.json");
+    let err = app
+        .execute_contract(Addr::unchecked(RECEIVER), contract.clone(), &burn)
+        .unwrap_err();
+    assert!(err.to_string().ends_with("unauthorized address"));
+
+    let res: SupplyResponse = query_fixture(&app, &contract, fixture!("query_supply.json"));
+    assert_eq!(res.amount, 1);
+}
+
+#[test]
+fn query_errors_are_redacted() {
+    let (mut app, contract) = setup();
+    let issue_denom: ExecuteMsg = fixture!("issue_denom_msg.json");
+    exec(&mut app, &contract, &issue_denom, TESTER);
+
+    let query_nft: QueryMsg = fixture!("query_nft.json");
+    let err = app
+        .query_wasm_smart::<QueryNFTResponse, _>(&contract, &query_nft)
+        .unwrap_err();
+    // On chain the tester only sees the code of the missing token, not the message.
+    assert!(err.to_string().ends_with("codespace: nft, code: 13"));
+    assert!(matches!(
+        CudosError::from(err),
+        CudosError::TokenNotFound(_)
+    ));
+}
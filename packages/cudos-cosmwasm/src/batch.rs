@@ -0,0 +1,226 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use cosmwasm_std::{from_slice, to_vec, CosmosMsg, StdError, StdResult, Storage};
+
+use crate::msg::{create_burn_nft_msg, create_mint_nft_msg, create_transfer_nft_msg, CudosMsg};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MintItem {
+    pub name: String,
+    pub uri: Option<String>,
+    pub data: Option<String>,
+    pub recipient: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TransferItem {
+    pub token_id: String,
+    pub from: String,
+    pub to: String,
+}
+
+pub fn create_batch_mint_msgs<M: From<CudosMsg>>(
+    denom_id: String,
+    items: impl IntoIterator<Item = MintItem>,
+    sender: String,
+    contract_address_signer: String,
+) -> Vec<CosmosMsg<M>> {
+    items
+        .into_iter()
+        .map(|item| {
+            create_mint_nft_msg(
+                denom_id.clone(),
+                item.name,
+                item.uri,
+                item.data,
+                item.recipient,
+                sender.clone(),
+                contract_address_signer.clone(),
+            )
+        })
+        .collect()
+}
+
+pub fn create_batch_transfer_msgs<M: From<CudosMsg>>(
+    denom_id: String,
+    items: impl IntoIterator<Item = TransferItem>,
+    sender: String,
+    contract_address_signer: String,
+) -> Vec<CosmosMsg<M>> {
+    items
+        .into_iter()
+        .map(|item| {
+            create_transfer_nft_msg(
+                denom_id.clone(),
+                item.token_id,
+                item.from,
+                item.to,
+                sender.clone(),
+                contract_address_signer.clone(),
+            )
+        })
+        .collect()
+}
+
+pub fn create_batch_burn_msgs<M: From<CudosMsg>>(
+    denom_id: String,
+    token_ids: impl IntoIterator<Item = String>,
+    sender: String,
+    contract_address_signer: String,
+) -> Vec<CosmosMsg<M>> {
+    token_ids
+        .into_iter()
+        .map(|token_id| {
+            create_burn_nft_msg(
+                denom_id.clone(),
+                token_id,
+                sender.clone(),
+                contract_address_signer.clone(),
+            )
+        })
+        .collect()
+}
+
+/// Position in a batch processed over several executions, e.g. when a whole drop would not
+/// fit in the gas limit of a single transaction. The contract persists it between executions
+/// and takes the next chunk of items on each call.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+pub struct BatchCursor {
+    /// Number of items already taken.
+    pub position: u64,
+    /// Number of items in the batch.
+    pub total: u64,
+}
+
+impl BatchCursor {
+    pub fn new(total: u64) -> Self {
+        BatchCursor { position: 0, total }
+    }
+
+    /// Takes up to `chunk_size` items following the cursor and advances it past them. `items`
+    /// must yield the same `total` items in the same order on every execution, which is checked
+    /// against their count.
+    pub fn next_chunk<I>(&mut self, items: I, chunk_size: usize) -> StdResult<Vec<I::Item>>
+    where
+        I: IntoIterator,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let items = items.into_iter();
+        if items.len() as u64 != self.total {
+            return Err(StdError::generic_err(format!(
+                "batch has {} items, the cursor was started for {}",
+                items.len(),
+                self.total
+            )));
+        }
+
+        let chunk: Vec<_> = items
+            .skip(self.position as usize)
+            .take(chunk_size)
+            .collect();
+        self.position += chunk.len() as u64;
+        Ok(chunk)
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.position >= self.total
+    }
+
+    /// Loads the cursor stored under `key`, failing if there is none.
+    pub fn load(storage: &dyn Storage, key: &[u8]) -> StdResult<Self> {
+        BatchCursor::may_load(storage, key)?.ok_or_else(|| StdError::not_found("BatchCursor"))
+    }
+
+    /// Loads the cursor stored under `key`, if any.
+    pub fn may_load(storage: &dyn Storage, key: &[u8]) -> StdResult<Option<Self>> {
+        storage.get(key).map(|value| from_slice(&value)).transpose()
+    }
+
+    pub fn save(&self, storage: &mut dyn Storage, key: &[u8]) -> StdResult<()> {
+        storage.set(key, &to_vec(self)?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::MockStorage;
+
+    const KEY: &[u8] = b"cursor";
+
+    #[test]
+    fn chunks_cover_the_batch_once() {
+        let items = || 0..5;
+        let mut cursor = BatchCursor::new(5);
+
+        assert_eq!(cursor.next_chunk(items(), 2).unwrap(), [0, 1]);
+        assert!(!cursor.is_done());
+        assert_eq!(cursor.next_chunk(items(), 2).unwrap(), [2, 3]);
+        // The last chunk is cut at the end of the batch.
+        assert_eq!(cursor.next_chunk(items(), 2).unwrap(), [4]);
+        assert!(cursor.is_done());
+        assert!(cursor.next_chunk(items(), 2).unwrap().is_empty());
+        assert_eq!(cursor.position, 5);
+    }
+
+    #[test]
+    fn chunk_ending_on_the_last_item_finishes_the_batch() {
+        let mut cursor = BatchCursor::new(4);
+        assert_eq!(
+            cursor
+                .next_chunk(vec!["a", "b", "c", "d"], 4)
+                .unwrap()
+                .len(),
+            4
+        );
+        assert!(cursor.is_done());
+
+        let mut empty = BatchCursor::new(0);
+        assert!(empty.is_done());
+        assert!(empty.next_chunk(Vec::<u8>::new(), 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn batches_of_another_size_are_rejected() {
+        let mut cursor = BatchCursor::new(5);
+        cursor.next_chunk(0..5, 2).unwrap();
+
+        let err = cursor.next_chunk(0..6, 2).unwrap_err();
+        assert_eq!(
+            err,
+            StdError::generic_err("batch has 6 items, the cursor was started for 5")
+        );
+        // The cursor does not move on errors.
+        assert_eq!(cursor.position, 2);
+        assert!(cursor.next_chunk(0..4, 2).is_err());
+    }
+
+    #[test]
+    fn cursor_is_saved_and_loaded() {
+        let mut storage = MockStorage::new();
+        assert_eq!(BatchCursor::may_load(&storage, KEY).unwrap(), None);
+        assert!(matches!(
+            BatchCursor::load(&storage, KEY),
+            Err(StdError::NotFound { .. })
+        ));
+
+        let mut cursor = BatchCursor::new(3);
+        cursor.next_chunk(0..3, 2).unwrap();
+        cursor.save(&mut storage, KEY).unwrap();
+
+        let mut loaded = BatchCursor::load(&storage, KEY).unwrap();
+        assert_eq!(
+            loaded,
+            BatchCursor {
+                position: 2,
+                total: 3
+            }
+        );
+        assert_eq!(loaded.next_chunk(0..3, 2).unwrap(), [2]);
+        assert!(loaded.is_done());
+        loaded.save(&mut storage, KEY).unwrap();
+        assert_eq!(BatchCursor::may_load(&storage, KEY).unwrap(), Some(loaded));
+    }
+}
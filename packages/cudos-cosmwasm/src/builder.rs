@@ -0,0 +1,582 @@
+use cosmwasm_std::{CosmosMsg, Env, MessageInfo};
+
+use crate::msg::{
+    create_approve_all_msg, create_approve_nft_msg, create_burn_nft_msg, create_edit_nft_msg,
+    create_issue_denom_msg_with_metadata, create_mint_nft_msg, create_revoke_msg,
+    create_transfer_denom_msg, create_transfer_nft_msg, CudosMsg, DenomMetadata,
+};
+
+// Typed builders for `CudosMsg`. `build` fills `sender` from `info.sender` and
+// `contract_address_signer` from `env.contract.address`, so they can't be swapped by mistake.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct IssueDenom {
+    id: String,
+    name: String,
+    symbol: String,
+    schema: Option<String>,
+    metadata: DenomMetadata,
+}
+
+impl IssueDenom {
+    pub fn new(id: impl Into<String>, name: impl Into<String>, symbol: impl Into<String>) -> Self {
+        IssueDenom {
+            id: id.into(),
+            name: name.into(),
+            symbol: symbol.into(),
+            schema: None,
+            metadata: DenomMetadata::default(),
+        }
+    }
+
+    pub fn schema(mut self, schema: impl Into<String>) -> Self {
+        self.schema = Some(schema.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.metadata.description = Some(description.into());
+        self
+    }
+
+    pub fn uri(mut self, uri: impl Into<String>) -> Self {
+        self.metadata.uri = Some(uri.into());
+        self
+    }
+
+    pub fn uri_hash(mut self, uri_hash: impl Into<String>) -> Self {
+        self.metadata.uri_hash = Some(uri_hash.into());
+        self
+    }
+
+    pub fn data(mut self, data: impl Into<String>) -> Self {
+        self.metadata.data = Some(data.into());
+        self
+    }
+
+    pub fn traits(mut self, traits: impl Into<String>) -> Self {
+        self.metadata.traits = Some(traits.into());
+        self
+    }
+
+    pub fn minter(mut self, minter: impl Into<String>) -> Self {
+        self.metadata.minter = Some(minter.into());
+        self
+    }
+
+    pub fn build<M: From<CudosMsg>>(self, env: &Env, info: &MessageInfo) -> CosmosMsg<M> {
+        create_issue_denom_msg_with_metadata(
+            self.id,
+            self.name,
+            self.symbol,
+            self.schema,
+            self.metadata,
+            info.sender.to_string(),
+            env.contract.address.to_string(),
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MintNft {
+    denom_id: String,
+    name: String,
+    uri: Option<String>,
+    data: Option<String>,
+    recipient: Option<String>,
+}
+
+impl MintNft {
+    pub fn new(denom_id: impl Into<String>, name: impl Into<String>) -> Self {
+        MintNft {
+            denom_id: denom_id.into(),
+            name: name.into(),
+            uri: None,
+            data: None,
+            recipient: None,
+        }
+    }
+
+    pub fn uri(mut self, uri: impl Into<String>) -> Self {
+        self.uri = Some(uri.into());
+        self
+    }
+
+    pub fn data(mut self, data: impl Into<String>) -> Self {
+        self.data = Some(data.into());
+        self
+    }
+
+    /// Defaults to the sender of the message when not set.
+    pub fn recipient(mut self, recipient: impl Into<String>) -> Self {
+        self.recipient = Some(recipient.into());
+        self
+    }
+
+    pub fn build<M: From<CudosMsg>>(self, env: &Env, info: &MessageInfo) -> CosmosMsg<M> {
+        let recipient = self.recipient.unwrap_or_else(|| info.sender.to_string());
+
+        create_mint_nft_msg(
+            self.denom_id,
+            self.name,
+            self.uri,
+            self.data,
+            recipient,
+            info.sender.to_string(),
+            env.contract.address.to_string(),
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EditNft {
+    denom_id: String,
+    token_id: String,
+    name: Option<String>,
+    uri: Option<String>,
+    data: Option<String>,
+}
+
+impl EditNft {
+    pub fn new(denom_id: impl Into<String>, token_id: impl Into<String>) -> Self {
+        EditNft {
+            denom_id: denom_id.into(),
+            token_id: token_id.into(),
+            name: None,
+            uri: None,
+            data: None,
+        }
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn uri(mut self, uri: impl Into<String>) -> Self {
+        self.uri = Some(uri.into());
+        self
+    }
+
+    pub fn data(mut self, data: impl Into<String>) -> Self {
+        self.data = Some(data.into());
+        self
+    }
+
+    pub fn build<M: From<CudosMsg>>(self, env: &Env, info: &MessageInfo) -> CosmosMsg<M> {
+        create_edit_nft_msg(
+            self.denom_id,
+            self.token_id,
+            self.name,
+            self.uri,
+            self.data,
+            info.sender.to_string(),
+            env.contract.address.to_string(),
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransferNft {
+    denom_id: String,
+    token_id: String,
+    from: Option<String>,
+    to: String,
+}
+
+impl TransferNft {
+    pub fn new(
+        denom_id: impl Into<String>,
+        token_id: impl Into<String>,
+        to: impl Into<String>,
+    ) -> Self {
+        TransferNft {
+            denom_id: denom_id.into(),
+            token_id: token_id.into(),
+            from: None,
+            to: to.into(),
+        }
+    }
+
+    /// Defaults to the sender of the message when not set.
+    pub fn from(mut self, from: impl Into<String>) -> Self {
+        self.from = Some(from.into());
+        self
+    }
+
+    pub fn build<M: From<CudosMsg>>(self, env: &Env, info: &MessageInfo) -> CosmosMsg<M> {
+        let from = self.from.unwrap_or_else(|| info.sender.to_string());
+
+        create_transfer_nft_msg(
+            self.denom_id,
+            self.token_id,
+            from,
+            self.to,
+            info.sender.to_string(),
+            env.contract.address.to_string(),
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransferDenom {
+    denom_id: String,
+    to: String,
+}
+
+impl TransferDenom {
+    pub fn new(denom_id: impl Into<String>, to: impl Into<String>) -> Self {
+        TransferDenom {
+            denom_id: denom_id.into(),
+            to: to.into(),
+        }
+    }
+
+    pub fn build<M: From<CudosMsg>>(self, env: &Env, info: &MessageInfo) -> CosmosMsg<M> {
+        create_transfer_denom_msg(
+            self.denom_id,
+            self.to,
+            info.sender.to_string(),
+            env.contract.address.to_string(),
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BurnNft {
+    denom_id: String,
+    token_id: String,
+}
+
+impl BurnNft {
+    pub fn new(denom_id: impl Into<String>, token_id: impl Into<String>) -> Self {
+        BurnNft {
+            denom_id: denom_id.into(),
+            token_id: token_id.into(),
+        }
+    }
+
+    pub fn build<M: From<CudosMsg>>(self, env: &Env, info: &MessageInfo) -> CosmosMsg<M> {
+        create_burn_nft_msg(
+            self.denom_id,
+            self.token_id,
+            info.sender.to_string(),
+            env.contract.address.to_string(),
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ApproveNft {
+    denom_id: String,
+    token_id: String,
+    approved_address: String,
+}
+
+impl ApproveNft {
+    pub fn new(
+        denom_id: impl Into<String>,
+        token_id: impl Into<String>,
+        approved_address: impl Into<String>,
+    ) -> Self {
+        ApproveNft {
+            denom_id: denom_id.into(),
+            token_id: token_id.into(),
+            approved_address: approved_address.into(),
+        }
+    }
+
+    pub fn build<M: From<CudosMsg>>(self, env: &Env, info: &MessageInfo) -> CosmosMsg<M> {
+        create_approve_nft_msg(
+            self.denom_id,
+            self.token_id,
+            self.approved_address,
+            info.sender.to_string(),
+            env.contract.address.to_string(),
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ApproveAll {
+    approved_operator: String,
+    approved: bool,
+}
+
+impl ApproveAll {
+    pub fn new(approved_operator: impl Into<String>, approved: bool) -> Self {
+        ApproveAll {
+            approved_operator: approved_operator.into(),
+            approved,
+        }
+    }
+
+    pub fn build<M: From<CudosMsg>>(self, env: &Env, info: &MessageInfo) -> CosmosMsg<M> {
+        create_approve_all_msg(
+            self.approved_operator,
+            self.approved,
+            info.sender.to_string(),
+            env.contract.address.to_string(),
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RevokeApproval {
+    denom_id: String,
+    token_id: String,
+    address_to_revoke: String,
+}
+
+impl RevokeApproval {
+    pub fn new(
+        denom_id: impl Into<String>,
+        token_id: impl Into<String>,
+        address_to_revoke: impl Into<String>,
+    ) -> Self {
+        RevokeApproval {
+            denom_id: denom_id.into(),
+            token_id: token_id.into(),
+            address_to_revoke: address_to_revoke.into(),
+        }
+    }
+
+    pub fn build<M: From<CudosMsg>>(self, env: &Env, info: &MessageInfo) -> CosmosMsg<M> {
+        create_revoke_msg(
+            self.denom_id,
+            self.token_id,
+            self.address_to_revoke,
+            info.sender.to_string(),
+            env.contract.address.to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
+
+    fn build<F: FnOnce(&Env, &MessageInfo) -> CosmosMsg<CudosMsg>>(build: F) -> CudosMsg {
+        match build(&mock_env(), &mock_info("sender", &[])) {
+            CosmosMsg::Custom(msg) => msg,
+            msg => panic!("unexpected message {:?}", msg),
+        }
+    }
+
+    fn s(value: &str) -> String {
+        value.to_string()
+    }
+
+    #[test]
+    fn issue_denom() {
+        assert_eq!(
+            build(|env, info| IssueDenom::new("kittens", "Kittens", "KIT").build(env, info)),
+            CudosMsg::IssueDenomMsg {
+                id: s("kittens"),
+                name: s("Kittens"),
+                symbol: s("KIT"),
+                schema: None,
+                description: None,
+                uri: None,
+                uri_hash: None,
+                data: None,
+                traits: None,
+                minter: None,
+                sender: s("sender"),
+                contract_address_signer: s(MOCK_CONTRACT_ADDR),
+            }
+        );
+
+        let msg = build(|env, info| {
+            IssueDenom::new("kittens", "Kittens", "KIT")
+                .schema("{}")
+                .description("cute")
+                .uri("ipfs://kittens")
+                .uri_hash("f00d")
+                .data("{\"legs\":4}")
+                .traits("transferable")
+                .minter("minter")
+                .build(env, info)
+        });
+        assert_eq!(
+            msg,
+            CudosMsg::IssueDenomMsg {
+                id: s("kittens"),
+                name: s("Kittens"),
+                symbol: s("KIT"),
+                schema: Some(s("{}")),
+                description: Some(s("cute")),
+                uri: Some(s("ipfs://kittens")),
+                uri_hash: Some(s("f00d")),
+                data: Some(s("{\"legs\":4}")),
+                traits: Some(s("transferable")),
+                minter: Some(s("minter")),
+                sender: s("sender"),
+                contract_address_signer: s(MOCK_CONTRACT_ADDR),
+            }
+        );
+    }
+
+    #[test]
+    fn mint_nft() {
+        assert_eq!(
+            build(|env, info| MintNft::new("kittens", "first").build(env, info)),
+            CudosMsg::MintNftMsg {
+                denom_id: s("kittens"),
+                name: s("first"),
+                uri: None,
+                data: None,
+                sender: s("sender"),
+                contract_address_signer: s(MOCK_CONTRACT_ADDR),
+                recipient: s("sender"),
+            }
+        );
+
+        let msg = build(|env, info| {
+            MintNft::new("kittens", "first")
+                .uri("ipfs://first")
+                .data("{}")
+                .recipient("recipient")
+                .build(env, info)
+        });
+        assert_eq!(
+            msg,
+            CudosMsg::MintNftMsg {
+                denom_id: s("kittens"),
+                name: s("first"),
+                uri: Some(s("ipfs://first")),
+                data: Some(s("{}")),
+                sender: s("sender"),
+                contract_address_signer: s(MOCK_CONTRACT_ADDR),
+                recipient: s("recipient"),
+            }
+        );
+    }
+
+    #[test]
+    fn edit_nft() {
+        assert_eq!(
+            build(|env, info| EditNft::new("kittens", "1").build(env, info)),
+            CudosMsg::EditNftMsg {
+                denom_id: s("kittens"),
+                token_id: s("1"),
+                name: None,
+                uri: None,
+                data: None,
+                sender: s("sender"),
+                contract_address_signer: s(MOCK_CONTRACT_ADDR),
+            }
+        );
+
+        let msg = build(|env, info| {
+            EditNft::new("kittens", "1")
+                .name("renamed")
+                .uri("ipfs://renamed")
+                .data("{}")
+                .build(env, info)
+        });
+        assert_eq!(
+            msg,
+            CudosMsg::EditNftMsg {
+                denom_id: s("kittens"),
+                token_id: s("1"),
+                name: Some(s("renamed")),
+                uri: Some(s("ipfs://renamed")),
+                data: Some(s("{}")),
+                sender: s("sender"),
+                contract_address_signer: s(MOCK_CONTRACT_ADDR),
+            }
+        );
+    }
+
+    #[test]
+    fn transfer_nft() {
+        assert_eq!(
+            build(|env, info| TransferNft::new("kittens", "1", "recipient").build(env, info)),
+            CudosMsg::TransferNftMsg {
+                denom_id: s("kittens"),
+                token_id: s("1"),
+                from: s("sender"),
+                to: s("recipient"),
+                sender: s("sender"),
+                contract_address_signer: s(MOCK_CONTRACT_ADDR),
+            }
+        );
+
+        let msg = build(|env, info| {
+            TransferNft::new("kittens", "1", "recipient")
+                .from("owner")
+                .build(env, info)
+        });
+        assert!(matches!(msg, CudosMsg::TransferNftMsg { from, .. } if from == "owner"));
+    }
+
+    #[test]
+    fn transfer_denom() {
+        assert_eq!(
+            build(|env, info| TransferDenom::new("kittens", "recipient").build(env, info)),
+            CudosMsg::TransferDenomMsg {
+                denom_id: s("kittens"),
+                to: s("recipient"),
+                sender: s("sender"),
+                contract_address_signer: s(MOCK_CONTRACT_ADDR),
+            }
+        );
+    }
+
+    #[test]
+    fn burn_nft() {
+        assert_eq!(
+            build(|env, info| BurnNft::new("kittens", "1").build(env, info)),
+            CudosMsg::BurnNftMsg {
+                denom_id: s("kittens"),
+                token_id: s("1"),
+                sender: s("sender"),
+                contract_address_signer: s(MOCK_CONTRACT_ADDR),
+            }
+        );
+    }
+
+    #[test]
+    fn approve_nft() {
+        assert_eq!(
+            build(|env, info| ApproveNft::new("kittens", "1", "operator").build(env, info)),
+            CudosMsg::ApproveNftMsg {
+                denom_id: s("kittens"),
+                token_id: s("1"),
+                approved_address: s("operator"),
+                sender: s("sender"),
+                contract_address_signer: s(MOCK_CONTRACT_ADDR),
+            }
+        );
+    }
+
+    #[test]
+    fn approve_all() {
+        assert_eq!(
+            build(|env, info| ApproveAll::new("operator", false).build(env, info)),
+            CudosMsg::ApproveAllMsg {
+                approved_operator: s("operator"),
+                approved: false,
+                sender: s("sender"),
+                contract_address_signer: s(MOCK_CONTRACT_ADDR),
+            }
+        );
+    }
+
+    #[test]
+    fn revoke_approval() {
+        assert_eq!(
+            build(|env, info| RevokeApproval::new("kittens", "1", "operator").build(env, info)),
+            CudosMsg::RevokeApprovalMsg {
+                denom_id: s("kittens"),
+                token_id: s("1"),
+                address_to_revoke: s("operator"),
+                sender: s("sender"),
+                contract_address_signer: s(MOCK_CONTRACT_ADDR),
+            }
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use cosmwasm_std::{ContractResult, SystemResult};
+
+use crate::querier::CudosQuerier;
+use crate::query::{CudosQuery, PaginationRequest};
+
+/// A custom module of Cudos Node that contracts can call into.
+///
+/// The addressbook is missing because its only query, the lookup of an entry, fails in the same
+/// way whether the entry or the module is missing, so no query can detect it.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CudosCapability {
+    Nft,
+    Marketplace,
+    Gravity,
+    Mint,
+}
+
+impl CudosCapability {
+    /// A cheap query that succeeds, even on an empty chain, on every chain supporting the
+    /// capability.
+    fn probe(&self) -> CudosQuery {
+        match self {
+            CudosCapability::Nft => CudosQuery::QueryDenoms {
+                pagination: single_item(),
+            },
+            CudosCapability::Marketplace => CudosQuery::QueryListings {
+                pagination: single_item(),
+            },
+            CudosCapability::Gravity => CudosQuery::QueryBatchFees {},
+            CudosCapability::Mint => CudosQuery::MintParams {},
+        }
+    }
+}
+
+impl<'a> CudosQuerier<'a> {
+    /// Checks whether the chain answers the queries of `capability`, so that contracts deployed
+    /// on several chains can fall back when the Cudos bindings are missing.
+    ///
+    /// Only a successful answer to the probe counts. Errors are redacted by the chain, so an
+    /// error of the module cannot be told apart from a handler that does not know the query.
+    pub fn supports(&self, capability: CudosCapability) -> bool {
+        matches!(
+            self.querier.raw_query_cudos(capability.probe()),
+            SystemResult::Ok(ContractResult::Ok(_))
+        )
+    }
+}
+
+fn single_item() -> Option<PaginationRequest> {
+    Some(PaginationRequest {
+        key: None,
+        offset: None,
+        limit: Some(1),
+        count_total: None,
+        reverse: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cosmwasm_std::testing::MockQuerier;
+    use cosmwasm_std::QuerierWrapper;
+
+    use crate::testing::mock_cudos_dependencies;
+
+    const ALL: [CudosCapability; 4] = [
+        CudosCapability::Nft,
+        CudosCapability::Marketplace,
+        CudosCapability::Gravity,
+        CudosCapability::Mint,
+    ];
+
+    #[test]
+    fn mock_supports_only_the_emulated_modules() {
+        let deps = mock_cudos_dependencies();
+        let deps = deps.as_ref();
+        let querier = CudosQuerier::new(&deps.querier);
+
+        assert!(querier.supports(CudosCapability::Nft));
+        assert!(!querier.supports(CudosCapability::Marketplace));
+        assert!(!querier.supports(CudosCapability::Gravity));
+        assert!(!querier.supports(CudosCapability::Mint));
+    }
+
+    #[test]
+    fn chains_without_custom_queries_support_nothing() {
+        let mock = MockQuerier::<CudosQuery>::new(&[]);
+        let wrapper = QuerierWrapper::<CudosQuery>::new(&mock);
+        let querier = CudosQuerier::new(&wrapper);
+
+        for capability in ALL.iter() {
+            assert!(!querier.supports(*capability), "{:?}", capability);
+        }
+    }
+}
@@ -0,0 +1,305 @@
+use std::convert::TryFrom;
+
+use cosmwasm_std::StdError;
+use cw721::{
+    AllNftInfoResponse, Approval, ContractInfoResponse, Expiration, NftInfoResponse,
+    NumTokensResponse, OwnerOfResponse,
+};
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::error::CudosError;
+use crate::query::{Denom, DenomResponse, QueryNFTResponse, SupplyResponse, NFT};
+
+// Conversions from the responses of the NFT module to the cw721 responses. Approvals of the
+// NFT module never expire, so they are converted with `Expiration::Never`.
+//
+// The cw721 responses describe a token or a contract without naming it, so most of them can't
+// be converted back: `OwnerOfResponse` and `NftInfoResponse` each hold only a part of an `NFT`,
+// and `ContractInfoResponse` lacks the id, creator and schema of a `Denom`. The exceptions are
+// `NumTokensResponse` and `AllNftInfoResponse`, which becomes an `NFT` given its token id.
+
+/// The fields of an NFT that have no cw721 counterpart, used as the `extension` of
+/// `NftInfoResponse`.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, JsonSchema)]
+pub struct NftExtension {
+    pub name: Option<String>,
+    pub data: Option<String>,
+}
+
+impl From<&NFT> for OwnerOfResponse {
+    fn from(nft: &NFT) -> Self {
+        OwnerOfResponse {
+            owner: nft.owner.clone(),
+            approvals: nft
+                .approved_addresses
+                .iter()
+                .flatten()
+                .map(|spender| Approval {
+                    spender: spender.clone(),
+                    expires: Expiration::Never {},
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<NFT> for OwnerOfResponse {
+    fn from(nft: NFT) -> Self {
+        OwnerOfResponse::from(&nft)
+    }
+}
+
+impl From<QueryNFTResponse> for OwnerOfResponse {
+    fn from(res: QueryNFTResponse) -> Self {
+        OwnerOfResponse::from(&res.nft)
+    }
+}
+
+impl From<NFT> for NftInfoResponse<NftExtension> {
+    fn from(nft: NFT) -> Self {
+        NftInfoResponse {
+            token_uri: nft.uri,
+            extension: NftExtension {
+                name: nft.name,
+                data: nft.data,
+            },
+        }
+    }
+}
+
+impl From<QueryNFTResponse> for NftInfoResponse<NftExtension> {
+    fn from(res: QueryNFTResponse) -> Self {
+        NftInfoResponse::from(res.nft)
+    }
+}
+
+/// Uses the JSON `data` of the NFT as the extension, see `NFT::parse_data`.
+impl<T: DeserializeOwned> TryFrom<NFT> for NftInfoResponse<Option<T>> {
+    type Error = CudosError;
+
+    fn try_from(nft: NFT) -> Result<Self, Self::Error> {
+        Ok(NftInfoResponse {
+            extension: nft.parse_data()?,
+            token_uri: nft.uri,
+        })
+    }
+}
+
+impl<T: DeserializeOwned> TryFrom<QueryNFTResponse> for NftInfoResponse<Option<T>> {
+    type Error = CudosError;
+
+    fn try_from(res: QueryNFTResponse) -> Result<Self, Self::Error> {
+        NftInfoResponse::try_from(res.nft)
+    }
+}
+
+impl From<NFT> for AllNftInfoResponse<NftExtension> {
+    fn from(nft: NFT) -> Self {
+        AllNftInfoResponse {
+            access: OwnerOfResponse::from(&nft),
+            info: NftInfoResponse::from(nft),
+        }
+    }
+}
+
+impl From<QueryNFTResponse> for AllNftInfoResponse<NftExtension> {
+    fn from(res: QueryNFTResponse) -> Self {
+        AllNftInfoResponse::from(res.nft)
+    }
+}
+
+impl<T: DeserializeOwned> TryFrom<NFT> for AllNftInfoResponse<Option<T>> {
+    type Error = CudosError;
+
+    fn try_from(nft: NFT) -> Result<Self, Self::Error> {
+        Ok(AllNftInfoResponse {
+            access: OwnerOfResponse::from(&nft),
+            info: NftInfoResponse::try_from(nft)?,
+        })
+    }
+}
+
+impl<T: DeserializeOwned> TryFrom<QueryNFTResponse> for AllNftInfoResponse<Option<T>> {
+    type Error = CudosError;
+
+    fn try_from(res: QueryNFTResponse) -> Result<Self, Self::Error> {
+        AllNftInfoResponse::try_from(res.nft)
+    }
+}
+
+impl NFT {
+    /// Rebuilds the NFT `token_id` from its cw721 info, e.g. to mint a copy of a cw721 token.
+    /// Fails when an approval expires, as those of the NFT module can not.
+    pub fn from_cw721(
+        token_id: impl Into<String>,
+        info: AllNftInfoResponse<NftExtension>,
+    ) -> Result<NFT, CudosError> {
+        let approvals = info
+            .access
+            .approvals
+            .into_iter()
+            .map(|approval| match approval.expires {
+                Expiration::Never {} => Ok(approval.spender),
+                _ => Err(CudosError::Std(StdError::generic_err(format!(
+                    "approval of {} expires, but approvals of the NFT module can not expire",
+                    approval.spender
+                )))),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(NFT {
+            id: token_id.into(),
+            name: info.info.extension.name,
+            uri: info.info.token_uri,
+            data: info.info.extension.data,
+            owner: info.access.owner,
+            approved_addresses: if approvals.is_empty() {
+                None
+            } else {
+                Some(approvals)
+            },
+        })
+    }
+}
+
+impl From<SupplyResponse> for NumTokensResponse {
+    fn from(res: SupplyResponse) -> Self {
+        NumTokensResponse { count: res.amount }
+    }
+}
+
+impl From<NumTokensResponse> for SupplyResponse {
+    fn from(res: NumTokensResponse) -> Self {
+        SupplyResponse { amount: res.count }
+    }
+}
+
+impl From<Denom> for ContractInfoResponse {
+    fn from(denom: Denom) -> Self {
+        ContractInfoResponse {
+            name: denom.name,
+            symbol: denom.symbol,
+        }
+    }
+}
+
+impl From<DenomResponse> for ContractInfoResponse {
+    fn from(res: DenomResponse) -> Self {
+        ContractInfoResponse::from(res.denom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cosmwasm_std::Timestamp;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Meta {
+        level: u32,
+    }
+
+    fn nft(approved_addresses: Option<Vec<String>>) -> NFT {
+        NFT {
+            id: "1".to_string(),
+            name: Some("first".to_string()),
+            uri: Some("ipfs://first".to_string()),
+            data: Some(r#"{"level":3}"#.to_string()),
+            owner: "owner".to_string(),
+            approved_addresses,
+        }
+    }
+
+    fn approval(spender: &str) -> Approval {
+        Approval {
+            spender: spender.to_string(),
+            expires: Expiration::Never {},
+        }
+    }
+
+    #[test]
+    fn owner_of() {
+        let approved = Some(vec!["alice".to_string(), "bob".to_string()]);
+        assert_eq!(
+            OwnerOfResponse::from(nft(approved)),
+            OwnerOfResponse {
+                owner: "owner".to_string(),
+                approvals: vec![approval("alice"), approval("bob")],
+            }
+        );
+
+        for approved in [None, Some(vec![])] {
+            let res = OwnerOfResponse::from(QueryNFTResponse { nft: nft(approved) });
+            assert_eq!(res.owner, "owner");
+            assert!(res.approvals.is_empty());
+        }
+    }
+
+    #[test]
+    fn nft_info() {
+        assert_eq!(
+            NftInfoResponse::<NftExtension>::from(nft(None)),
+            NftInfoResponse {
+                token_uri: Some("ipfs://first".to_string()),
+                extension: NftExtension {
+                    name: Some("first".to_string()),
+                    data: Some(r#"{"level":3}"#.to_string()),
+                },
+            }
+        );
+
+        let info = NftInfoResponse::<Option<Meta>>::try_from(nft(None)).unwrap();
+        assert_eq!(info.extension, Some(Meta { level: 3 }));
+
+        let mut malformed = nft(None);
+        malformed.data = Some("oops".to_string());
+        assert!(matches!(
+            NftInfoResponse::<Option<Meta>>::try_from(malformed),
+            Err(CudosError::InvalidNftData { .. })
+        ));
+    }
+
+    #[test]
+    fn all_nft_info_round_trips() {
+        for approved in [None, Some(vec!["alice".to_string()])] {
+            let original = nft(approved);
+            let info = AllNftInfoResponse::<NftExtension>::from(original.clone());
+            assert_eq!(info.access, OwnerOfResponse::from(&original));
+            assert_eq!(NFT::from_cw721("1", info).unwrap(), original);
+        }
+    }
+
+    #[test]
+    fn expiring_approvals_are_not_converted() {
+        let mut info = AllNftInfoResponse::<NftExtension>::from(nft(None));
+        info.access.approvals.push(Approval {
+            spender: "alice".to_string(),
+            expires: Expiration::AtTime(Timestamp::from_seconds(1)),
+        });
+        let err = NFT::from_cw721("1", info).unwrap_err();
+        assert!(err.to_string().contains("can not expire"));
+    }
+
+    #[test]
+    fn counts_and_contract_info() {
+        let count = NumTokensResponse::from(SupplyResponse { amount: 7 });
+        assert_eq!(count.count, 7);
+        assert_eq!(SupplyResponse::from(count).amount, 7);
+
+        let denom = Denom {
+            id: "kittens".to_string(),
+            name: "Kittens".to_string(),
+            symbol: "KIT".to_string(),
+            ..Denom::default()
+        };
+        assert_eq!(
+            ContractInfoResponse::from(DenomResponse { denom }),
+            ContractInfoResponse {
+                name: "Kittens".to_string(),
+                symbol: "KIT".to_string(),
+            }
+        );
+    }
+}
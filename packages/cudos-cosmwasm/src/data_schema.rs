@@ -0,0 +1,827 @@
+// Checks NFT `data` against the JSON schema stored in its denom. Only `core` and `alloc` are
+// used here, so the checks do not pull std into the contract, and the JSON is parsed by
+// `json`, which keeps floating point out of the contract.
+//
+// The supported keywords are `type`, `enum`, `const`, `minLength`, `maxLength`, `minimum`,
+// `maximum`, `exclusiveMinimum`, `exclusiveMaximum`, `minItems`, `maxItems`, `items` (a single
+// schema for all items), `required`, `properties`, `additionalProperties`, `allOf`, `anyOf` and
+// `oneOf`. The validation keywords of JSON Schema that are not implemented are rejected, so that
+// a schema is never accepted without being enforced. Other keywords, such as `title` or
+// `description`, are ignored.
+//
+// Floating point is not available to contracts, so numbers are compared as integers only: the
+// range keywords must be integers and data that is not an integer never satisfies them.
+
+use alloc::format;
+use alloc::string::String;
+
+use crate::json::{self, Map, Number, Value};
+
+use crate::error::CudosError;
+use crate::msg::CudosMsg;
+use crate::querier::CudosQuerier;
+use crate::query::Denom;
+use crate::validation::ValidationError;
+
+/// Checks `data` against the schema of `denom`. Denoms without a schema accept any data.
+pub fn validate_data_schema(denom: &Denom, data: &str) -> Result<(), ValidationError> {
+    let schema = match denom.schema.as_deref() {
+        Some(schema) if !schema.trim().is_empty() => schema,
+        _ => return Ok(()),
+    };
+
+    let schema = json::parse(schema).map_err(|reason| ValidationError::InvalidSchema {
+        denom_id: denom.id.clone(),
+        reason,
+    })?;
+    let data = json::parse(data).map_err(|err| ValidationError::SchemaMismatch {
+        denom_id: denom.id.clone(),
+        reason: format!("data is not valid JSON: {}", err),
+    })?;
+
+    check(&schema, &data, "data").map_err(|failure| match failure {
+        Failure::Schema(reason) => ValidationError::InvalidSchema {
+            denom_id: denom.id.clone(),
+            reason,
+        },
+        Failure::Data(reason) => ValidationError::SchemaMismatch {
+            denom_id: denom.id.clone(),
+            reason,
+        },
+    })
+}
+
+impl<'a> CudosQuerier<'a> {
+    /// Checks the `data` of a `MintNftMsg`, `EditNftMsg` or `MarketplaceMintNftMsg` against the
+    /// schema of its denom, fetched with `query_denom_by_id`. Other messages, and messages
+    /// without data, pass as is.
+    pub fn validate_nft_data(&self, msg: &CudosMsg) -> Result<(), CudosError> {
+        let (denom_id, data) = match msg {
+            CudosMsg::MintNftMsg {
+                denom_id,
+                data: Some(data),
+                ..
+            }
+            | CudosMsg::EditNftMsg {
+                denom_id,
+                data: Some(data),
+                ..
+            }
+            | CudosMsg::MarketplaceMintNftMsg {
+                denom_id,
+                data: Some(data),
+                ..
+            } if !data.is_empty() => (denom_id, data),
+            _ => return Ok(()),
+        };
+
+        let denom = self.try_query_denom_by_id(denom_id.as_str())?.denom;
+        Ok(validate_data_schema(&denom, data)?)
+    }
+}
+
+enum Failure {
+    // The schema itself is malformed or uses an unsupported keyword.
+    Schema(String),
+    // The data does not match the schema.
+    Data(String),
+}
+
+// Validation keywords of JSON Schema that the checks do not implement.
+const UNSUPPORTED_KEYWORDS: &[&str] = &[
+    "$ref",
+    "$dynamicRef",
+    "$recursiveRef",
+    "additionalItems",
+    "contains",
+    "dependencies",
+    "dependentRequired",
+    "dependentSchemas",
+    "else",
+    "format",
+    "if",
+    "maxContains",
+    "maxProperties",
+    "minContains",
+    "minProperties",
+    "multipleOf",
+    "not",
+    "pattern",
+    "patternProperties",
+    "prefixItems",
+    "propertyNames",
+    "then",
+    "unevaluatedItems",
+    "unevaluatedProperties",
+    "uniqueItems",
+];
+
+fn check(schema: &Value, value: &Value, path: &str) -> Result<(), Failure> {
+    let schema = match schema {
+        Value::Bool(true) => return Ok(()),
+        Value::Bool(false) => return Err(Failure::Data(format!("{}: no value is allowed", path))),
+        Value::Object(schema) => schema,
+        _ => {
+            return Err(Failure::Schema(format!(
+                "{}: schema must be an object or a boolean",
+                path
+            )))
+        }
+    };
+
+    if let Some(keyword) = UNSUPPORTED_KEYWORDS
+        .iter()
+        .find(|keyword| schema.contains_key(**keyword))
+    {
+        return Err(Failure::Schema(format!(
+            "{}: `{}` is not supported",
+            path, keyword
+        )));
+    }
+
+    if let Some(ty) = schema.get("type") {
+        check_type(ty, value, path)?;
+    }
+    if let Some(options) = schema.get("enum") {
+        let options = options
+            .as_array()
+            .ok_or_else(|| Failure::Schema(format!("{}: `enum` must be an array", path)))?;
+        if !options.contains(value) {
+            return Err(Failure::Data(format!(
+                "{}: value is not one of the allowed values",
+                path
+            )));
+        }
+    }
+    if let Some(expected) = schema.get("const") {
+        if expected != value {
+            return Err(Failure::Data(format!(
+                "{}: value must be {}",
+                path, expected
+            )));
+        }
+    }
+
+    match value {
+        Value::String(s) => check_string(schema, s, path)?,
+        Value::Number(n) => check_number(schema, n, path)?,
+        Value::Array(items) => check_array(schema, items, path)?,
+        Value::Object(fields) => check_object(schema, fields, path)?,
+        Value::Null | Value::Bool(_) => {}
+    }
+
+    check_combinators(schema, value, path)
+}
+
+fn check_type(ty: &Value, value: &Value, path: &str) -> Result<(), Failure> {
+    let matches = match ty {
+        Value::String(name) => type_matches(name, value, path)?,
+        Value::Array(names) => {
+            let mut matches = false;
+            for name in names {
+                let name = name.as_str().ok_or_else(|| {
+                    Failure::Schema(format!("{}: `type` must contain strings", path))
+                })?;
+                matches |= type_matches(name, value, path)?;
+            }
+            matches
+        }
+        _ => {
+            return Err(Failure::Schema(format!(
+                "{}: `type` must be a string or an array",
+                path
+            )))
+        }
+    };
+
+    if !matches {
+        return Err(Failure::Data(format!(
+            "{}: expected {}, got {}",
+            path,
+            ty,
+            type_name(value)
+        )));
+    }
+    Ok(())
+}
+
+fn type_matches(name: &str, value: &Value, path: &str) -> Result<bool, Failure> {
+    Ok(match name {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => match value {
+            Value::Number(n) => n.as_integer().is_some(),
+            _ => false,
+        },
+        _ => {
+            return Err(Failure::Schema(format!(
+                "{}: unknown type {:?}",
+                path, name
+            )))
+        }
+    })
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn check_string(schema: &Map, s: &str, path: &str) -> Result<(), Failure> {
+    let len = s.chars().count() as u64;
+    if let Some(min) = keyword_u64(schema, "minLength", path)? {
+        if len < min {
+            return Err(Failure::Data(format!(
+                "{}: length must be at least {}",
+                path, min
+            )));
+        }
+    }
+    if let Some(max) = keyword_u64(schema, "maxLength", path)? {
+        if len > max {
+            return Err(Failure::Data(format!(
+                "{}: length must be at most {}",
+                path, max
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn check_number(schema: &Map, n: &Number, path: &str) -> Result<(), Failure> {
+    let minimum = keyword_integer(schema, "minimum", path)?;
+    let maximum = keyword_integer(schema, "maximum", path)?;
+    let exclusive_minimum = keyword_integer(schema, "exclusiveMinimum", path)?;
+    let exclusive_maximum = keyword_integer(schema, "exclusiveMaximum", path)?;
+    let n = match n.as_integer() {
+        Some(n) => n,
+        None if minimum.is_none()
+            && maximum.is_none()
+            && exclusive_minimum.is_none()
+            && exclusive_maximum.is_none() =>
+        {
+            return Ok(())
+        }
+        None => {
+            return Err(Failure::Data(format!(
+                "{}: must be an integer to be checked against a range",
+                path
+            )))
+        }
+    };
+
+    if let Some(min) = minimum {
+        if n < min {
+            return Err(Failure::Data(format!("{}: must be at least {}", path, min)));
+        }
+    }
+    if let Some(max) = maximum {
+        if n > max {
+            return Err(Failure::Data(format!("{}: must be at most {}", path, max)));
+        }
+    }
+    if let Some(min) = exclusive_minimum {
+        if n <= min {
+            return Err(Failure::Data(format!(
+                "{}: must be greater than {}",
+                path, min
+            )));
+        }
+    }
+    if let Some(max) = exclusive_maximum {
+        if n >= max {
+            return Err(Failure::Data(format!(
+                "{}: must be less than {}",
+                path, max
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn check_array(schema: &Map, items: &[Value], path: &str) -> Result<(), Failure> {
+    let len = items.len() as u64;
+    if let Some(min) = keyword_u64(schema, "minItems", path)? {
+        if len < min {
+            return Err(Failure::Data(format!(
+                "{}: must have at least {} items",
+                path, min
+            )));
+        }
+    }
+    if let Some(max) = keyword_u64(schema, "maxItems", path)? {
+        if len > max {
+            return Err(Failure::Data(format!(
+                "{}: must have at most {} items",
+                path, max
+            )));
+        }
+    }
+    if let Some(item_schema) = schema.get("items") {
+        if item_schema.is_array() {
+            return Err(Failure::Schema(format!(
+                "{}: `items` must be a single schema, the tuple form is not supported",
+                path
+            )));
+        }
+        for (i, item) in items.iter().enumerate() {
+            check(item_schema, item, &format!("{}/{}", path, i))?;
+        }
+    }
+    Ok(())
+}
+
+fn check_object(schema: &Map, fields: &Map, path: &str) -> Result<(), Failure> {
+    if let Some(required) = schema.get("required") {
+        let required = required
+            .as_array()
+            .ok_or_else(|| Failure::Schema(format!("{}: `required` must be an array", path)))?;
+        for name in required {
+            let name = name.as_str().ok_or_else(|| {
+                Failure::Schema(format!("{}: `required` must contain strings", path))
+            })?;
+            if !fields.contains_key(name) {
+                return Err(Failure::Data(format!(
+                    "{}: missing required property {:?}",
+                    path, name
+                )));
+            }
+        }
+    }
+
+    let properties = match schema.get("properties") {
+        None => None,
+        Some(Value::Object(properties)) => Some(properties),
+        Some(_) => {
+            return Err(Failure::Schema(format!(
+                "{}: `properties` must be an object",
+                path
+            )))
+        }
+    };
+    let additional = schema.get("additionalProperties");
+
+    for (name, field) in fields {
+        let field_path = format!("{}/{}", path, name);
+        match properties.and_then(|properties| properties.get(name)) {
+            Some(field_schema) => check(field_schema, field, &field_path)?,
+            None => match additional {
+                Some(Value::Bool(false)) => {
+                    return Err(Failure::Data(format!(
+                        "{}: unexpected property {:?}",
+                        path, name
+                    )))
+                }
+                Some(additional) => check(additional, field, &field_path)?,
+                None => {}
+            },
+        }
+    }
+    Ok(())
+}
+
+fn check_combinators(schema: &Map, value: &Value, path: &str) -> Result<(), Failure> {
+    if let Some(schemas) = keyword_array(schema, "allOf", path)? {
+        for sub in schemas {
+            check(sub, value, path)?;
+        }
+    }
+    if let Some(schemas) = keyword_array(schema, "anyOf", path)? {
+        if count_matches(schemas, value, path)? == 0 {
+            return Err(Failure::Data(format!(
+                "{}: value matches none of the `anyOf` schemas",
+                path
+            )));
+        }
+    }
+    if let Some(schemas) = keyword_array(schema, "oneOf", path)? {
+        let count = count_matches(schemas, value, path)?;
+        if count != 1 {
+            return Err(Failure::Data(format!(
+                "{}: value must match exactly one of the `oneOf` schemas, matched {}",
+                path, count
+            )));
+        }
+    }
+    Ok(())
+}
+
+// Counts the sub-schemas matched by `value`, failing only when one of them is malformed.
+fn count_matches(schemas: &[Value], value: &Value, path: &str) -> Result<usize, Failure> {
+    let mut count = 0;
+    for sub in schemas {
+        match check(sub, value, path) {
+            Ok(()) => count += 1,
+            Err(Failure::Data(_)) => {}
+            Err(failure) => return Err(failure),
+        }
+    }
+    Ok(count)
+}
+
+fn keyword_u64(schema: &Map, key: &str, path: &str) -> Result<Option<u64>, Failure> {
+    match schema.get(key) {
+        None => Ok(None),
+        Some(value) => value.as_u64().map(Some).ok_or_else(|| {
+            Failure::Schema(format!(
+                "{}: `{}` must be a non-negative integer",
+                path, key
+            ))
+        }),
+    }
+}
+
+fn keyword_integer(schema: &Map, key: &str, path: &str) -> Result<Option<i128>, Failure> {
+    match schema.get(key) {
+        None => Ok(None),
+        Some(Value::Number(Number::Integer(n))) => Ok(Some(*n)),
+        Some(_) => Err(Failure::Schema(format!(
+            "{}: `{}` must be an integer",
+            path, key
+        ))),
+    }
+}
+
+fn keyword_array<'s>(
+    schema: &'s Map,
+    key: &str,
+    path: &str,
+) -> Result<Option<&'s [Value]>, Failure> {
+    match schema.get(key) {
+        None => Ok(None),
+        Some(Value::Array(schemas)) => Ok(Some(schemas)),
+        Some(_) => Err(Failure::Schema(format!(
+            "{}: `{}` must be an array",
+            path, key
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::mock_cudos_dependencies;
+
+    fn validate(schema: &str, data: &str) -> Result<(), ValidationError> {
+        let denom = Denom {
+            id: "kittens".to_string(),
+            schema: Some(schema.to_string()),
+            ..Denom::default()
+        };
+        validate_data_schema(&denom, data)
+    }
+
+    fn assert_accepts(schema: &str, data: &str) {
+        if let Err(err) = validate(schema, data) {
+            panic!("{} should accept {}: {}", schema, data, err);
+        }
+    }
+
+    fn assert_mismatch(schema: &str, data: &str, expected: &str) {
+        match validate(schema, data) {
+            Err(ValidationError::SchemaMismatch { reason, .. }) => assert_eq!(reason, expected),
+            res => panic!("{} should reject {}, got {:?}", schema, data, res),
+        }
+    }
+
+    fn assert_invalid_schema(schema: &str, data: &str, expected: &str) {
+        match validate(schema, data) {
+            Err(ValidationError::InvalidSchema { reason, .. }) => assert_eq!(reason, expected),
+            res => panic!("{} should be rejected, got {:?}", schema, res),
+        }
+    }
+
+    #[test]
+    fn denoms_without_schema_accept_any_data() {
+        let denom = Denom::default();
+        assert_eq!(validate_data_schema(&denom, "not json"), Ok(()));
+        assert_accepts(" ", "not json");
+    }
+
+    #[test]
+    fn malformed_json_is_rejected() {
+        assert!(matches!(
+            validate("{", "{}"),
+            Err(ValidationError::InvalidSchema { .. })
+        ));
+        assert!(matches!(
+            validate("{}", "{"),
+            Err(ValidationError::SchemaMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn boolean_schemas() {
+        assert_accepts("true", "1");
+        assert_mismatch("false", "1", "data: no value is allowed");
+        assert_invalid_schema("1", "1", "data: schema must be an object or a boolean");
+    }
+
+    #[test]
+    fn type_keyword() {
+        assert_accepts(r#"{"type": "null"}"#, "null");
+        assert_accepts(r#"{"type": "boolean"}"#, "true");
+        assert_accepts(r#"{"type": "object"}"#, "{}");
+        assert_accepts(r#"{"type": "array"}"#, "[]");
+        assert_accepts(r#"{"type": "string"}"#, r#""a""#);
+        assert_accepts(r#"{"type": "number"}"#, "1.5");
+        assert_accepts(r#"{"type": "integer"}"#, "-1");
+        assert_accepts(r#"{"type": "integer"}"#, "18446744073709551615");
+        assert_accepts(r#"{"type": ["string", "null"]}"#, "null");
+        assert_mismatch(
+            r#"{"type": "integer"}"#,
+            "1.5",
+            r#"data: expected "integer", got number"#,
+        );
+        assert_mismatch(
+            r#"{"type": ["string", "null"]}"#,
+            "1",
+            r#"data: expected ["string","null"], got number"#,
+        );
+        assert_invalid_schema(
+            r#"{"type": "float"}"#,
+            "{}",
+            r#"data: unknown type "float""#,
+        );
+        assert_invalid_schema(
+            r#"{"type": [1]}"#,
+            "{}",
+            "data: `type` must contain strings",
+        );
+        assert_invalid_schema(
+            r#"{"type": 1}"#,
+            "{}",
+            "data: `type` must be a string or an array",
+        );
+    }
+
+    #[test]
+    fn enum_keyword() {
+        let schema = r#"{"enum": ["red", 1]}"#;
+        assert_accepts(schema, r#""red""#);
+        assert_accepts(schema, "1");
+        assert_mismatch(
+            schema,
+            r#""blue""#,
+            "data: value is not one of the allowed values",
+        );
+        assert_invalid_schema(r#"{"enum": "red"}"#, "{}", "data: `enum` must be an array");
+    }
+
+    #[test]
+    fn const_keyword() {
+        let schema = r#"{"const": {"a": 1}}"#;
+        assert_accepts(schema, r#"{"a": 1}"#);
+        assert_mismatch(schema, r#"{"a": 2}"#, r#"data: value must be {"a":1}"#);
+    }
+
+    #[test]
+    fn length_keywords() {
+        let schema = r#"{"minLength": 2, "maxLength": 3}"#;
+        // Lengths count characters, not bytes.
+        assert_accepts(schema, r#""éé""#);
+        assert_accepts(schema, r#""abc""#);
+        assert_mismatch(schema, r#""a""#, "data: length must be at least 2");
+        assert_mismatch(schema, r#""abcd""#, "data: length must be at most 3");
+        assert_invalid_schema(
+            r#"{"minLength": -1}"#,
+            r#""a""#,
+            "data: `minLength` must be a non-negative integer",
+        );
+    }
+
+    #[test]
+    fn range_keywords() {
+        assert_accepts(r#"{"minimum": -1}"#, "-1");
+        assert_mismatch(r#"{"minimum": -1}"#, "-2", "data: must be at least -1");
+        assert_accepts(r#"{"maximum": 3}"#, "3");
+        assert_mismatch(r#"{"maximum": 3}"#, "4", "data: must be at most 3");
+        assert_accepts(r#"{"exclusiveMinimum": 0}"#, "1");
+        assert_mismatch(
+            r#"{"exclusiveMinimum": 0}"#,
+            "0",
+            "data: must be greater than 0",
+        );
+        assert_accepts(r#"{"exclusiveMaximum": 0}"#, "-1");
+        assert_mismatch(
+            r#"{"exclusiveMaximum": 0}"#,
+            "0",
+            "data: must be less than 0",
+        );
+        // Integers beyond i64 are compared exactly.
+        assert_mismatch(
+            r#"{"maximum": 9223372036854775807}"#,
+            "18446744073709551615",
+            "data: must be at most 9223372036854775807",
+        );
+    }
+
+    #[test]
+    fn range_keywords_only_compare_integers() {
+        assert_accepts(r#"{"type": "number"}"#, "0.5");
+        assert_mismatch(
+            r#"{"minimum": 0}"#,
+            "0.5",
+            "data: must be an integer to be checked against a range",
+        );
+        assert_invalid_schema(
+            r#"{"minimum": 0.5}"#,
+            "1",
+            "data: `minimum` must be an integer",
+        );
+        assert_invalid_schema(
+            r#"{"exclusiveMaximum": "1"}"#,
+            "1",
+            "data: `exclusiveMaximum` must be an integer",
+        );
+    }
+
+    #[test]
+    fn item_count_keywords() {
+        let schema = r#"{"minItems": 1, "maxItems": 2}"#;
+        assert_accepts(schema, "[1, 2]");
+        assert_mismatch(schema, "[]", "data: must have at least 1 items");
+        assert_mismatch(schema, "[1, 2, 3]", "data: must have at most 2 items");
+    }
+
+    #[test]
+    fn items_keyword() {
+        let schema = r#"{"items": {"type": "string"}}"#;
+        assert_accepts(schema, r#"["a", "b"]"#);
+        assert_mismatch(
+            schema,
+            r#"["a", 1]"#,
+            "data/1: expected \"string\", got number",
+        );
+        assert_invalid_schema(
+            r#"{"items": [{"type": "string"}]}"#,
+            "[]",
+            "data: `items` must be a single schema, the tuple form is not supported",
+        );
+    }
+
+    #[test]
+    fn required_keyword() {
+        let schema = r#"{"required": ["level"]}"#;
+        assert_accepts(schema, r#"{"level": 1}"#);
+        assert_mismatch(schema, "{}", r#"data: missing required property "level""#);
+        assert_invalid_schema(
+            r#"{"required": "level"}"#,
+            "{}",
+            "data: `required` must be an array",
+        );
+        assert_invalid_schema(
+            r#"{"required": [1]}"#,
+            "{}",
+            "data: `required` must contain strings",
+        );
+    }
+
+    #[test]
+    fn properties_keywords() {
+        let schema = r#"{"properties": {"level": {"type": "integer"}}}"#;
+        assert_accepts(schema, r#"{"level": 1, "name": "a"}"#);
+        assert_mismatch(
+            schema,
+            r#"{"level": "1"}"#,
+            r#"data/level: expected "integer", got string"#,
+        );
+
+        let closed = r#"{"properties": {"level": {}}, "additionalProperties": false}"#;
+        assert_accepts(closed, r#"{"level": 1}"#);
+        assert_mismatch(
+            closed,
+            r#"{"name": "a"}"#,
+            r#"data: unexpected property "name""#,
+        );
+
+        let typed = r#"{"additionalProperties": {"type": "string"}}"#;
+        assert_accepts(typed, r#"{"name": "a"}"#);
+        assert_mismatch(
+            typed,
+            r#"{"name": 1}"#,
+            r#"data/name: expected "string", got number"#,
+        );
+
+        assert_invalid_schema(
+            r#"{"properties": []}"#,
+            "{}",
+            "data: `properties` must be an object",
+        );
+    }
+
+    #[test]
+    fn combinator_keywords() {
+        let all_of = r#"{"allOf": [{"minimum": 1}, {"maximum": 2}]}"#;
+        assert_accepts(all_of, "2");
+        assert_mismatch(all_of, "3", "data: must be at most 2");
+
+        let any_of = r#"{"anyOf": [{"type": "string"}, {"minimum": 1}]}"#;
+        assert_accepts(any_of, "1");
+        assert_mismatch(
+            any_of,
+            "0",
+            "data: value matches none of the `anyOf` schemas",
+        );
+
+        let one_of = r#"{"oneOf": [{"type": "integer"}, {"minimum": 1}]}"#;
+        assert_accepts(one_of, "0");
+        assert_mismatch(
+            one_of,
+            "1",
+            "data: value must match exactly one of the `oneOf` schemas, matched 2",
+        );
+
+        // A malformed sub-schema is reported even when another one matches.
+        assert_invalid_schema(
+            r#"{"anyOf": [{}, {"type": 1}]}"#,
+            "{}",
+            "data: `type` must be a string or an array",
+        );
+        assert_invalid_schema(r#"{"oneOf": {}}"#, "{}", "data: `oneOf` must be an array");
+    }
+
+    #[test]
+    fn unsupported_keywords_are_rejected() {
+        for keyword in UNSUPPORTED_KEYWORDS {
+            let schema = format!(r#"{{"properties": {{"a": {{"{}": true}}}}}}"#, keyword);
+            match validate(&schema, r#"{"a": 1}"#) {
+                Err(ValidationError::InvalidSchema { reason, .. }) => {
+                    assert_eq!(reason, format!("data/a: `{}` is not supported", keyword))
+                }
+                res => panic!("`{}` should be rejected, got {:?}", keyword, res),
+            }
+        }
+    }
+
+    #[test]
+    fn annotations_are_ignored() {
+        assert_accepts(
+            r#"{"$schema": "http://json-schema.org/draft-07/schema#", "title": "Kitten",
+                "description": "A kitten", "default": {}, "examples": [], "$id": "kitten"}"#,
+            "{}",
+        );
+    }
+
+    #[test]
+    fn validates_the_data_of_nft_msgs() {
+        let mut deps = mock_cudos_dependencies();
+        deps.querier
+            .nft
+            .issue_denom(
+                "kittens",
+                "Kittens",
+                "KIT",
+                Some(r#"{"type": "object", "required": ["level"]}"#.to_string()),
+                "creator",
+            )
+            .unwrap();
+        let deps = deps.as_ref();
+        let querier = CudosQuerier::new(&deps.querier);
+        let mint = |data: Option<&str>| CudosMsg::MintNftMsg {
+            denom_id: "kittens".to_string(),
+            name: "first".to_string(),
+            uri: None,
+            data: data.map(str::to_string),
+            recipient: "owner".to_string(),
+            sender: "owner".to_string(),
+            contract_address_signer: "contract".to_string(),
+        };
+
+        assert!(querier.validate_nft_data(&mint(None)).is_ok());
+        assert!(querier
+            .validate_nft_data(&mint(Some(r#"{"level": 1}"#)))
+            .is_ok());
+        assert!(matches!(
+            querier.validate_nft_data(&mint(Some("{}"))),
+            Err(CudosError::Validation(
+                ValidationError::SchemaMismatch { .. }
+            ))
+        ));
+
+        let missing = CudosMsg::EditNftMsg {
+            denom_id: "puppies".to_string(),
+            token_id: "1".to_string(),
+            name: None,
+            uri: None,
+            data: Some("{}".to_string()),
+            sender: "owner".to_string(),
+            contract_address_signer: "contract".to_string(),
+        };
+        // The missing denom is redacted to the code of `invalid denom`.
+        assert!(matches!(
+            querier.validate_nft_data(&missing),
+            Err(CudosError::InvalidDenom(_))
+        ));
+    }
+}
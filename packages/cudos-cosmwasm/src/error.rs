@@ -0,0 +1,205 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+use crate::validation::ValidationError;
+
+/// Errors of the Cudos bindings, with the known failures of the NFT module classified so
+/// contracts can branch on them instead of matching strings.
+#[derive(Error, Debug, PartialEq)]
+pub enum CudosError {
+    #[error("{0}")]
+    Std(StdError),
+
+    #[error("{0}")]
+    Validation(#[from] ValidationError),
+
+    #[error("{0}")]
+    DenomNotFound(String),
+
+    #[error("{0}")]
+    TokenNotFound(String),
+
+    #[error("{0}")]
+    Unauthorized(String),
+
+    #[error("{0}")]
+    AlreadyExists(String),
+
+    /// The NFT module rejected a denom with the code of `invalid denom`, which it returns for
+    /// malformed, duplicated and missing denoms alike.
+    #[error("{0}")]
+    InvalidDenom(String),
+
+    #[error("invalid data of nft {token_id:?}: {reason}")]
+    InvalidNftData { token_id: String, reason: String },
+
+    #[error("{0}")]
+    InvalidReply(String),
+
+    #[error("{0}")]
+    InvalidEvent(String),
+
+    #[error("nft {denom_id}/{token_id} was not received, it is owned by {owner}")]
+    NotReceived {
+        denom_id: String,
+        token_id: String,
+        owner: String,
+    },
+
+    #[error("ReceiveNft was executed by {caller}, which is not trusted to send nfts")]
+    UntrustedCaller { caller: String },
+}
+
+impl CudosError {
+    pub fn is_not_found(&self) -> bool {
+        matches!(
+            self,
+            CudosError::DenomNotFound(_) | CudosError::TokenNotFound(_)
+        )
+    }
+
+    /// Classifies an error message returned by the NFT module.
+    ///
+    /// wasmd redacts the errors of submessages and queries to `codespace: <x>, code: <n>`
+    /// before a contract sees them, so on chain the failures are classified by the code the
+    /// module registered them with. The full messages of the module, classified by their
+    /// text, only reach contracts running off-chain, e.g. in tests against an emulator that
+    /// does not redact them.
+    pub fn from_module_error(msg: impl Into<String>) -> Self {
+        let msg = msg.into();
+        if let Some((codespace, code)) = parse_redacted(&msg) {
+            return CudosError::from_code(codespace, code, msg.clone());
+        }
+        let lower = msg.to_lowercase();
+
+        if lower.contains("not found nft") || is_unknown_nft(&lower) {
+            CudosError::TokenNotFound(msg)
+        } else if lower.contains("not found denom") || lower.contains("unknown nft collection") {
+            CudosError::DenomNotFound(msg)
+        } else if lower.contains("unauthorized") {
+            CudosError::Unauthorized(msg)
+        } else if lower.contains("already exists") {
+            CudosError::AlreadyExists(msg)
+        } else {
+            CudosError::Std(StdError::generic_err(msg))
+        }
+    }
+
+    // The module reports malformed, duplicated and missing denoms with the same code, so it
+    // can only be classified as `InvalidDenom`.
+    fn from_code(codespace: &str, code: u32, msg: String) -> Self {
+        if codespace != NFT_CODESPACE {
+            return CudosError::Std(StdError::generic_err(msg));
+        }
+        match code {
+            CODE_UNKNOWN_NFT => CudosError::TokenNotFound(msg),
+            CODE_UNKNOWN_COLLECTION => CudosError::DenomNotFound(msg),
+            CODE_INVALID_DENOM => CudosError::InvalidDenom(msg),
+            CODE_UNAUTHORIZED => CudosError::Unauthorized(msg),
+            CODE_NFT_ALREADY_EXISTS => CudosError::AlreadyExists(msg),
+            _ => CudosError::Std(StdError::generic_err(msg)),
+        }
+    }
+}
+
+/// Codespace of the errors registered by the NFT module.
+pub const NFT_CODESPACE: &str = "nft";
+
+// Codes of the errors registered in x/nft/types/errors.go.
+pub(crate) const CODE_UNKNOWN_COLLECTION: u32 = 10;
+pub(crate) const CODE_NFT_ALREADY_EXISTS: u32 = 12;
+pub(crate) const CODE_UNKNOWN_NFT: u32 = 13;
+pub(crate) const CODE_UNAUTHORIZED: u32 = 15;
+pub(crate) const CODE_INVALID_DENOM: u32 = 16;
+
+// Reads the codespace and code of an error redacted by wasmd. Query errors are prefixed by
+// `QuerierWrapper`, so the redacted part is looked up anywhere in the message.
+fn parse_redacted(msg: &str) -> Option<(&str, u32)> {
+    let start = msg.find("codespace: ")?;
+    let (codespace, code) = msg[start + "codespace: ".len()..].split_once(", code: ")?;
+    Some((codespace, code.parse().ok()?))
+}
+
+// "unknown nft" is the registered error for a missing token, while "unknown nft collection"
+// is returned for a missing denom.
+fn is_unknown_nft(msg: &str) -> bool {
+    msg.match_indices("unknown nft")
+        .any(|(i, m)| !msg[i + m.len()..].starts_with(" collection"))
+}
+
+impl From<StdError> for CudosError {
+    fn from(err: StdError) -> Self {
+        match err {
+            StdError::GenericErr { msg, .. } => CudosError::from_module_error(msg),
+            err => CudosError::Std(err),
+        }
+    }
+}
+
+impl From<CudosError> for StdError {
+    fn from(err: CudosError) -> Self {
+        match err {
+            CudosError::Std(err) => err,
+            err => StdError::generic_err(err.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_module_messages() {
+        let err = CudosError::from_module_error("not found NFT: denom, tokenID: 1: unknown nft");
+        assert!(matches!(err, CudosError::TokenNotFound(_)));
+        let err = CudosError::from_module_error("not found denomID: denom: invalid denom");
+        assert!(matches!(err, CudosError::DenomNotFound(_)));
+        let err = CudosError::from_module_error("denom: unknown nft collection");
+        assert!(matches!(err, CudosError::DenomNotFound(_)));
+        let err = CudosError::from_module_error("addr is not the owner: unauthorized address");
+        assert!(matches!(err, CudosError::Unauthorized(_)));
+        let err = CudosError::from_module_error("denomID denom has already exists: invalid denom");
+        assert!(matches!(err, CudosError::AlreadyExists(_)));
+        let err = CudosError::from_module_error("invalid denom");
+        assert!(matches!(err, CudosError::Std(_)));
+    }
+
+    #[test]
+    fn classifies_redacted_errors_by_code() {
+        let err = CudosError::from_module_error("codespace: nft, code: 13");
+        assert_eq!(
+            err,
+            CudosError::TokenNotFound("codespace: nft, code: 13".to_string())
+        );
+        let err = CudosError::from_module_error("codespace: nft, code: 10");
+        assert!(matches!(err, CudosError::DenomNotFound(_)));
+        let err = CudosError::from_module_error("codespace: nft, code: 16");
+        assert!(matches!(err, CudosError::InvalidDenom(_)));
+        assert!(!err.is_not_found());
+        let err = CudosError::from_module_error("codespace: nft, code: 15");
+        assert!(matches!(err, CudosError::Unauthorized(_)));
+        let err = CudosError::from_module_error("codespace: nft, code: 12");
+        assert!(matches!(err, CudosError::AlreadyExists(_)));
+        let err = CudosError::from_module_error("codespace: nft, code: 17");
+        assert!(matches!(err, CudosError::Std(_)));
+    }
+
+    #[test]
+    fn classifies_redacted_query_errors() {
+        let err: CudosError =
+            StdError::generic_err("Querier contract error: codespace: nft, code: 13").into();
+        assert!(err.is_not_found());
+    }
+
+    #[test]
+    fn ignores_codes_of_other_modules() {
+        // Unauthorized in the sdk codespace, and a failed contract execution.
+        let err = CudosError::from_module_error("codespace: sdk, code: 4");
+        assert!(matches!(err, CudosError::Std(_)));
+        let err = CudosError::from_module_error("codespace: wasm, code: 5");
+        assert!(matches!(err, CudosError::Std(_)));
+        let err = CudosError::from_module_error("codespace: nft, code: not a number");
+        assert!(matches!(err, CudosError::Std(_)));
+    }
+}
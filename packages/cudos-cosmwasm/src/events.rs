@@ -0,0 +1,309 @@
+use std::convert::TryFrom;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use cosmwasm_std::Event;
+
+use crate::error::CudosError;
+
+// Events emitted by the x/nft module, as seen by contracts in the replies of submessages and by
+// indexers in the transaction results.
+
+pub const EVENT_TYPE_ISSUE_DENOM: &str = "issue_denom";
+pub const EVENT_TYPE_MINT_NFT: &str = "mint_nft";
+pub const EVENT_TYPE_EDIT_NFT: &str = "edit_nft";
+pub const EVENT_TYPE_TRANSFER_NFT: &str = "transfer_nft";
+pub const EVENT_TYPE_TRANSFER_DENOM: &str = "transfer_denom";
+pub const EVENT_TYPE_BURN_NFT: &str = "burn_nft";
+pub const EVENT_TYPE_APPROVE_NFT: &str = "approve_nft";
+pub const EVENT_TYPE_APPROVE_ALL: &str = "approve_all";
+pub const EVENT_TYPE_REVOKE_NFT: &str = "revoke_nft";
+
+pub const ATTRIBUTE_KEY_DENOM_ID: &str = "denom_id";
+pub const ATTRIBUTE_KEY_DENOM_NAME: &str = "denom_name";
+pub const ATTRIBUTE_KEY_CREATOR: &str = "creator";
+pub const ATTRIBUTE_KEY_TOKEN_ID: &str = "token_id";
+pub const ATTRIBUTE_KEY_TOKEN_URI: &str = "token_uri";
+pub const ATTRIBUTE_KEY_RECIPIENT: &str = "recipient";
+pub const ATTRIBUTE_KEY_OWNER: &str = "owner";
+pub const ATTRIBUTE_KEY_FROM: &str = "from";
+pub const ATTRIBUTE_KEY_TO: &str = "to";
+pub const ATTRIBUTE_KEY_SENDER: &str = "sender";
+pub const ATTRIBUTE_KEY_APPROVED_ADDRESS: &str = "approved_address";
+pub const ATTRIBUTE_KEY_OPERATOR: &str = "operator";
+pub const ATTRIBUTE_KEY_APPROVED: &str = "approved";
+pub const ATTRIBUTE_KEY_REVOKED_ADDRESS: &str = "revoked_address";
+
+/// An event of the x/nft module that can be parsed from a generic `Event`.
+pub trait CudosEvent: for<'a> TryFrom<&'a Event, Error = CudosError> {
+    const EVENT_TYPE: &'static str;
+}
+
+/// Parses every event of type `T::EVENT_TYPE` in `events`, e.g. all the tokens minted in a
+/// transaction.
+pub fn parse_events<'a, T: CudosEvent + 'a>(
+    events: &'a [Event],
+) -> impl Iterator<Item = Result<T, CudosError>> + 'a {
+    events
+        .iter()
+        .filter(|event| event.ty == T::EVENT_TYPE)
+        .map(T::try_from)
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct IssuedDenom {
+    pub denom_id: String,
+    pub denom_name: String,
+    pub creator: String,
+}
+
+impl CudosEvent for IssuedDenom {
+    const EVENT_TYPE: &'static str = EVENT_TYPE_ISSUE_DENOM;
+}
+
+impl TryFrom<&Event> for IssuedDenom {
+    type Error = CudosError;
+
+    fn try_from(event: &Event) -> Result<Self, Self::Error> {
+        expect_type(event, Self::EVENT_TYPE)?;
+        Ok(IssuedDenom {
+            denom_id: attribute(event, ATTRIBUTE_KEY_DENOM_ID)?,
+            denom_name: attribute(event, ATTRIBUTE_KEY_DENOM_NAME)?,
+            creator: attribute(event, ATTRIBUTE_KEY_CREATOR)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MintedNft {
+    pub denom_id: String,
+    pub token_id: String,
+    pub token_uri: String,
+    pub recipient: String,
+}
+
+impl CudosEvent for MintedNft {
+    const EVENT_TYPE: &'static str = EVENT_TYPE_MINT_NFT;
+}
+
+impl TryFrom<&Event> for MintedNft {
+    type Error = CudosError;
+
+    fn try_from(event: &Event) -> Result<Self, Self::Error> {
+        expect_type(event, Self::EVENT_TYPE)?;
+        Ok(MintedNft {
+            denom_id: attribute(event, ATTRIBUTE_KEY_DENOM_ID)?,
+            token_id: attribute(event, ATTRIBUTE_KEY_TOKEN_ID)?,
+            token_uri: attribute(event, ATTRIBUTE_KEY_TOKEN_URI)?,
+            recipient: attribute(event, ATTRIBUTE_KEY_RECIPIENT)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EditedNft {
+    pub denom_id: String,
+    pub token_id: String,
+    pub token_uri: String,
+    pub owner: String,
+}
+
+impl CudosEvent for EditedNft {
+    const EVENT_TYPE: &'static str = EVENT_TYPE_EDIT_NFT;
+}
+
+impl TryFrom<&Event> for EditedNft {
+    type Error = CudosError;
+
+    fn try_from(event: &Event) -> Result<Self, Self::Error> {
+        expect_type(event, Self::EVENT_TYPE)?;
+        Ok(EditedNft {
+            denom_id: attribute(event, ATTRIBUTE_KEY_DENOM_ID)?,
+            token_id: attribute(event, ATTRIBUTE_KEY_TOKEN_ID)?,
+            token_uri: attribute(event, ATTRIBUTE_KEY_TOKEN_URI)?,
+            owner: attribute(event, ATTRIBUTE_KEY_OWNER)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TransferredNft {
+    pub denom_id: String,
+    pub token_id: String,
+    pub from: String,
+    pub to: String,
+}
+
+impl CudosEvent for TransferredNft {
+    const EVENT_TYPE: &'static str = EVENT_TYPE_TRANSFER_NFT;
+}
+
+impl TryFrom<&Event> for TransferredNft {
+    type Error = CudosError;
+
+    fn try_from(event: &Event) -> Result<Self, Self::Error> {
+        expect_type(event, Self::EVENT_TYPE)?;
+        Ok(TransferredNft {
+            denom_id: attribute(event, ATTRIBUTE_KEY_DENOM_ID)?,
+            token_id: attribute(event, ATTRIBUTE_KEY_TOKEN_ID)?,
+            from: attribute(event, ATTRIBUTE_KEY_FROM)?,
+            to: attribute(event, ATTRIBUTE_KEY_TO)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TransferredDenom {
+    pub denom_id: String,
+    pub sender: String,
+    pub recipient: String,
+}
+
+impl CudosEvent for TransferredDenom {
+    const EVENT_TYPE: &'static str = EVENT_TYPE_TRANSFER_DENOM;
+}
+
+impl TryFrom<&Event> for TransferredDenom {
+    type Error = CudosError;
+
+    fn try_from(event: &Event) -> Result<Self, Self::Error> {
+        expect_type(event, Self::EVENT_TYPE)?;
+        Ok(TransferredDenom {
+            denom_id: attribute(event, ATTRIBUTE_KEY_DENOM_ID)?,
+            sender: attribute(event, ATTRIBUTE_KEY_SENDER)?,
+            recipient: attribute(event, ATTRIBUTE_KEY_RECIPIENT)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BurnedNft {
+    pub denom_id: String,
+    pub token_id: String,
+    pub owner: String,
+}
+
+impl CudosEvent for BurnedNft {
+    const EVENT_TYPE: &'static str = EVENT_TYPE_BURN_NFT;
+}
+
+impl TryFrom<&Event> for BurnedNft {
+    type Error = CudosError;
+
+    fn try_from(event: &Event) -> Result<Self, Self::Error> {
+        expect_type(event, Self::EVENT_TYPE)?;
+        Ok(BurnedNft {
+            denom_id: attribute(event, ATTRIBUTE_KEY_DENOM_ID)?,
+            token_id: attribute(event, ATTRIBUTE_KEY_TOKEN_ID)?,
+            owner: attribute(event, ATTRIBUTE_KEY_OWNER)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ApprovedNft {
+    pub denom_id: String,
+    pub token_id: String,
+    pub sender: String,
+    pub approved_address: String,
+}
+
+impl CudosEvent for ApprovedNft {
+    const EVENT_TYPE: &'static str = EVENT_TYPE_APPROVE_NFT;
+}
+
+impl TryFrom<&Event> for ApprovedNft {
+    type Error = CudosError;
+
+    fn try_from(event: &Event) -> Result<Self, Self::Error> {
+        expect_type(event, Self::EVENT_TYPE)?;
+        Ok(ApprovedNft {
+            denom_id: attribute(event, ATTRIBUTE_KEY_DENOM_ID)?,
+            token_id: attribute(event, ATTRIBUTE_KEY_TOKEN_ID)?,
+            sender: attribute(event, ATTRIBUTE_KEY_SENDER)?,
+            approved_address: attribute(event, ATTRIBUTE_KEY_APPROVED_ADDRESS)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ApprovedAll {
+    pub sender: String,
+    pub operator: String,
+    pub approved: bool,
+}
+
+impl CudosEvent for ApprovedAll {
+    const EVENT_TYPE: &'static str = EVENT_TYPE_APPROVE_ALL;
+}
+
+impl TryFrom<&Event> for ApprovedAll {
+    type Error = CudosError;
+
+    fn try_from(event: &Event) -> Result<Self, Self::Error> {
+        expect_type(event, Self::EVENT_TYPE)?;
+        Ok(ApprovedAll {
+            sender: attribute(event, ATTRIBUTE_KEY_SENDER)?,
+            operator: attribute(event, ATTRIBUTE_KEY_OPERATOR)?,
+            approved: parse_bool(event, ATTRIBUTE_KEY_APPROVED)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RevokedNft {
+    pub denom_id: String,
+    pub token_id: String,
+    pub sender: String,
+    pub revoked_address: String,
+}
+
+impl CudosEvent for RevokedNft {
+    const EVENT_TYPE: &'static str = EVENT_TYPE_REVOKE_NFT;
+}
+
+impl TryFrom<&Event> for RevokedNft {
+    type Error = CudosError;
+
+    fn try_from(event: &Event) -> Result<Self, Self::Error> {
+        expect_type(event, Self::EVENT_TYPE)?;
+        Ok(RevokedNft {
+            denom_id: attribute(event, ATTRIBUTE_KEY_DENOM_ID)?,
+            token_id: attribute(event, ATTRIBUTE_KEY_TOKEN_ID)?,
+            sender: attribute(event, ATTRIBUTE_KEY_SENDER)?,
+            revoked_address: attribute(event, ATTRIBUTE_KEY_REVOKED_ADDRESS)?,
+        })
+    }
+}
+
+fn expect_type(event: &Event, ty: &str) -> Result<(), CudosError> {
+    if event.ty != ty {
+        return Err(CudosError::InvalidEvent(format!(
+            "expected `{}` event, got `{}`",
+            ty, event.ty
+        )));
+    }
+    Ok(())
+}
+
+fn attribute(event: &Event, key: &str) -> Result<String, CudosError> {
+    event
+        .attributes
+        .iter()
+        .find(|attribute| attribute.key == key)
+        .map(|attribute| attribute.value.clone())
+        .ok_or_else(|| {
+            CudosError::InvalidEvent(format!("`{}` event has no `{}` attribute", event.ty, key))
+        })
+}
+
+fn parse_bool(event: &Event, key: &str) -> Result<bool, CudosError> {
+    let value = attribute(event, key)?;
+    value.parse().map_err(|_| {
+        CudosError::InvalidEvent(format!(
+            "attribute `{}` of `{}` event is not a boolean: {:?}",
+            key, event.ty, value
+        ))
+    })
+}
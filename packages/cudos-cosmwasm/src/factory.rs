@@ -0,0 +1,503 @@
+use cosmwasm_std::{Addr, Binary, Coin, CosmosMsg, Env, MessageInfo, StdResult};
+
+use crate::batch::{
+    create_batch_burn_msgs, create_batch_mint_msgs, create_batch_transfer_msgs, MintItem,
+    TransferItem,
+};
+use crate::msg::{
+    create_approve_all_msg, create_approve_nft_msg, create_burn_nft_msg, create_buy_nft_msg,
+    create_cancel_send_to_eth_msg, create_create_address_msg, create_delete_address_msg,
+    create_edit_nft_msg, create_issue_denom_msg, create_issue_denom_msg_with_metadata,
+    create_marketplace_mint_nft_msg, create_mint_nft_msg, create_publish_collection_msg,
+    create_publish_nft_msg, create_revoke_msg, create_send_to_eth_msg, create_transfer_denom_msg,
+    create_transfer_nft_msg, create_unpublish_collection_msg, create_unpublish_nft_msg,
+    create_update_address_msg, create_update_price_msg, CudosMsg, DenomMetadata,
+};
+use crate::query::Royalty;
+use crate::receiver::create_send_nft_msgs;
+
+/// Creates `CudosMsg`s with `sender` and `contract_address_signer` already filled in.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CudosMsgFactory {
+    sender: String,
+    contract_address_signer: String,
+}
+
+impl CudosMsgFactory {
+    /// Messages are sent on behalf of `info.sender` and signed by the executing contract.
+    pub fn new(env: &Env, info: &MessageInfo) -> Self {
+        CudosMsgFactory {
+            sender: info.sender.to_string(),
+            contract_address_signer: env.contract.address.to_string(),
+        }
+    }
+
+    /// Messages are sent on behalf of `signer` and signed by it, e.g. for a contract acting on
+    /// its own NFTs from `reply` or `sudo` where there is no `MessageInfo`.
+    pub fn from_signer(signer: &Addr) -> Self {
+        CudosMsgFactory {
+            sender: signer.to_string(),
+            contract_address_signer: signer.to_string(),
+        }
+    }
+
+    pub fn sender(&self) -> &str {
+        &self.sender
+    }
+
+    pub fn contract_address_signer(&self) -> &str {
+        &self.contract_address_signer
+    }
+
+    pub fn issue_denom<M: From<CudosMsg>>(
+        &self,
+        id: impl Into<String>,
+        name: impl Into<String>,
+        symbol: impl Into<String>,
+        schema: Option<String>,
+    ) -> CosmosMsg<M> {
+        create_issue_denom_msg(
+            id.into(),
+            name.into(),
+            symbol.into(),
+            schema,
+            self.sender.clone(),
+            self.contract_address_signer.clone(),
+        )
+    }
+
+    pub fn issue_denom_with_metadata<M: From<CudosMsg>>(
+        &self,
+        id: impl Into<String>,
+        name: impl Into<String>,
+        symbol: impl Into<String>,
+        schema: Option<String>,
+        metadata: DenomMetadata,
+    ) -> CosmosMsg<M> {
+        create_issue_denom_msg_with_metadata(
+            id.into(),
+            name.into(),
+            symbol.into(),
+            schema,
+            metadata,
+            self.sender.clone(),
+            self.contract_address_signer.clone(),
+        )
+    }
+
+    pub fn mint_nft<M: From<CudosMsg>>(
+        &self,
+        denom_id: impl Into<String>,
+        name: impl Into<String>,
+        uri: Option<String>,
+        data: Option<String>,
+        recipient: impl Into<String>,
+    ) -> CosmosMsg<M> {
+        create_mint_nft_msg(
+            denom_id.into(),
+            name.into(),
+            uri,
+            data,
+            recipient.into(),
+            self.sender.clone(),
+            self.contract_address_signer.clone(),
+        )
+    }
+
+    pub fn edit_nft<M: From<CudosMsg>>(
+        &self,
+        denom_id: impl Into<String>,
+        token_id: impl Into<String>,
+        name: Option<String>,
+        uri: Option<String>,
+        data: Option<String>,
+    ) -> CosmosMsg<M> {
+        create_edit_nft_msg(
+            denom_id.into(),
+            token_id.into(),
+            name,
+            uri,
+            data,
+            self.sender.clone(),
+            self.contract_address_signer.clone(),
+        )
+    }
+
+    pub fn transfer_nft<M: From<CudosMsg>>(
+        &self,
+        denom_id: impl Into<String>,
+        token_id: impl Into<String>,
+        from: impl Into<String>,
+        to: impl Into<String>,
+    ) -> CosmosMsg<M> {
+        create_transfer_nft_msg(
+            denom_id.into(),
+            token_id.into(),
+            from.into(),
+            to.into(),
+            self.sender.clone(),
+            self.contract_address_signer.clone(),
+        )
+    }
+
+    /// Transfers the NFT to `contract` and notifies it with `ReceiveNft`, see
+    /// `create_send_nft_msgs`.
+    pub fn send_nft<M: From<CudosMsg>>(
+        &self,
+        denom_id: impl Into<String>,
+        token_id: impl Into<String>,
+        from: impl Into<String>,
+        contract: impl Into<String>,
+        msg: Binary,
+    ) -> StdResult<Vec<CosmosMsg<M>>> {
+        create_send_nft_msgs(
+            denom_id.into(),
+            token_id.into(),
+            from.into(),
+            contract.into(),
+            msg,
+            self.sender.clone(),
+            self.contract_address_signer.clone(),
+        )
+    }
+
+    pub fn transfer_denom<M: From<CudosMsg>>(
+        &self,
+        denom_id: impl Into<String>,
+        to: impl Into<String>,
+    ) -> CosmosMsg<M> {
+        create_transfer_denom_msg(
+            denom_id.into(),
+            to.into(),
+            self.sender.clone(),
+            self.contract_address_signer.clone(),
+        )
+    }
+
+    pub fn burn_nft<M: From<CudosMsg>>(
+        &self,
+        denom_id: impl Into<String>,
+        token_id: impl Into<String>,
+    ) -> CosmosMsg<M> {
+        create_burn_nft_msg(
+            denom_id.into(),
+            token_id.into(),
+            self.sender.clone(),
+            self.contract_address_signer.clone(),
+        )
+    }
+
+    pub fn approve_nft<M: From<CudosMsg>>(
+        &self,
+        denom_id: impl Into<String>,
+        token_id: impl Into<String>,
+        approved_address: impl Into<String>,
+    ) -> CosmosMsg<M> {
+        create_approve_nft_msg(
+            denom_id.into(),
+            token_id.into(),
+            approved_address.into(),
+            self.sender.clone(),
+            self.contract_address_signer.clone(),
+        )
+    }
+
+    pub fn approve_all<M: From<CudosMsg>>(
+        &self,
+        approved_operator: impl Into<String>,
+        approved: bool,
+    ) -> CosmosMsg<M> {
+        create_approve_all_msg(
+            approved_operator.into(),
+            approved,
+            self.sender.clone(),
+            self.contract_address_signer.clone(),
+        )
+    }
+
+    pub fn revoke_approval<M: From<CudosMsg>>(
+        &self,
+        denom_id: impl Into<String>,
+        token_id: impl Into<String>,
+        address_to_revoke: impl Into<String>,
+    ) -> CosmosMsg<M> {
+        create_revoke_msg(
+            denom_id.into(),
+            token_id.into(),
+            address_to_revoke.into(),
+            self.sender.clone(),
+            self.contract_address_signer.clone(),
+        )
+    }
+
+    pub fn batch_mint_nft<M: From<CudosMsg>>(
+        &self,
+        denom_id: impl Into<String>,
+        items: impl IntoIterator<Item = MintItem>,
+    ) -> Vec<CosmosMsg<M>> {
+        create_batch_mint_msgs(
+            denom_id.into(),
+            items,
+            self.sender.clone(),
+            self.contract_address_signer.clone(),
+        )
+    }
+
+    pub fn batch_transfer_nft<M: From<CudosMsg>>(
+        &self,
+        denom_id: impl Into<String>,
+        items: impl IntoIterator<Item = TransferItem>,
+    ) -> Vec<CosmosMsg<M>> {
+        create_batch_transfer_msgs(
+            denom_id.into(),
+            items,
+            self.sender.clone(),
+            self.contract_address_signer.clone(),
+        )
+    }
+
+    pub fn batch_burn_nft<M: From<CudosMsg>>(
+        &self,
+        denom_id: impl Into<String>,
+        token_ids: impl IntoIterator<Item = String>,
+    ) -> Vec<CosmosMsg<M>> {
+        create_batch_burn_msgs(
+            denom_id.into(),
+            token_ids,
+            self.sender.clone(),
+            self.contract_address_signer.clone(),
+        )
+    }
+
+    pub fn publish_collection<M: From<CudosMsg>>(
+        &self,
+        denom_id: impl Into<String>,
+        mint_royalties: Vec<Royalty>,
+        resale_royalties: Vec<Royalty>,
+    ) -> CosmosMsg<M> {
+        create_publish_collection_msg(
+            denom_id.into(),
+            mint_royalties,
+            resale_royalties,
+            self.sender.clone(),
+            self.contract_address_signer.clone(),
+        )
+    }
+
+    pub fn unpublish_collection<M: From<CudosMsg>>(&self, id: u64) -> CosmosMsg<M> {
+        create_unpublish_collection_msg(
+            id,
+            self.sender.clone(),
+            self.contract_address_signer.clone(),
+        )
+    }
+
+    pub fn publish_nft<M: From<CudosMsg>>(
+        &self,
+        denom_id: impl Into<String>,
+        token_id: impl Into<String>,
+        price: Coin,
+    ) -> CosmosMsg<M> {
+        create_publish_nft_msg(
+            denom_id.into(),
+            token_id.into(),
+            price,
+            self.sender.clone(),
+            self.contract_address_signer.clone(),
+        )
+    }
+
+    pub fn unpublish_nft<M: From<CudosMsg>>(&self, id: u64) -> CosmosMsg<M> {
+        create_unpublish_nft_msg(
+            id,
+            self.sender.clone(),
+            self.contract_address_signer.clone(),
+        )
+    }
+
+    pub fn buy_nft<M: From<CudosMsg>>(
+        &self,
+        id: u64,
+        recipient: impl Into<String>,
+    ) -> CosmosMsg<M> {
+        create_buy_nft_msg(
+            id,
+            recipient.into(),
+            self.sender.clone(),
+            self.contract_address_signer.clone(),
+        )
+    }
+
+    pub fn update_price<M: From<CudosMsg>>(&self, id: u64, price: Coin) -> CosmosMsg<M> {
+        create_update_price_msg(
+            id,
+            price,
+            self.sender.clone(),
+            self.contract_address_signer.clone(),
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn marketplace_mint_nft<M: From<CudosMsg>>(
+        &self,
+        denom_id: impl Into<String>,
+        name: impl Into<String>,
+        uri: Option<String>,
+        data: Option<String>,
+        uid: Option<String>,
+        price: Coin,
+        recipient: impl Into<String>,
+    ) -> CosmosMsg<M> {
+        create_marketplace_mint_nft_msg(
+            denom_id.into(),
+            name.into(),
+            uri,
+            data,
+            uid,
+            price,
+            recipient.into(),
+            self.sender.clone(),
+            self.contract_address_signer.clone(),
+        )
+    }
+
+    pub fn create_address<M: From<CudosMsg>>(
+        &self,
+        network: impl Into<String>,
+        label: impl Into<String>,
+        value: impl Into<String>,
+    ) -> CosmosMsg<M> {
+        create_create_address_msg(
+            network.into(),
+            label.into(),
+            value.into(),
+            self.sender.clone(),
+            self.contract_address_signer.clone(),
+        )
+    }
+
+    pub fn update_address<M: From<CudosMsg>>(
+        &self,
+        network: impl Into<String>,
+        label: impl Into<String>,
+        value: impl Into<String>,
+    ) -> CosmosMsg<M> {
+        create_update_address_msg(
+            network.into(),
+            label.into(),
+            value.into(),
+            self.sender.clone(),
+            self.contract_address_signer.clone(),
+        )
+    }
+
+    pub fn delete_address<M: From<CudosMsg>>(
+        &self,
+        network: impl Into<String>,
+        label: impl Into<String>,
+    ) -> CosmosMsg<M> {
+        create_delete_address_msg(
+            network.into(),
+            label.into(),
+            self.sender.clone(),
+            self.contract_address_signer.clone(),
+        )
+    }
+
+    pub fn send_to_eth<M: From<CudosMsg>>(
+        &self,
+        eth_dest: impl Into<String>,
+        amount: Coin,
+        bridge_fee: Coin,
+    ) -> CosmosMsg<M> {
+        create_send_to_eth_msg(
+            eth_dest.into(),
+            amount,
+            bridge_fee,
+            self.sender.clone(),
+            self.contract_address_signer.clone(),
+        )
+    }
+
+    pub fn cancel_send_to_eth<M: From<CudosMsg>>(&self, transaction_id: u64) -> CosmosMsg<M> {
+        create_cancel_send_to_eth_msg(
+            transaction_id,
+            self.sender.clone(),
+            self.contract_address_signer.clone(),
+        )
+    }
+}
+
+/// Variants of the methods above taking the addresses as `Addr`, so that only addresses the
+/// contract got from `deps.api.addr_validate` (or `validate_addr`) can be passed.
+impl CudosMsgFactory {
+    pub fn mint_nft_to<M: From<CudosMsg>>(
+        &self,
+        denom_id: impl Into<String>,
+        name: impl Into<String>,
+        uri: Option<String>,
+        data: Option<String>,
+        recipient: &Addr,
+    ) -> CosmosMsg<M> {
+        self.mint_nft(denom_id, name, uri, data, recipient.as_str())
+    }
+
+    pub fn transfer_nft_to<M: From<CudosMsg>>(
+        &self,
+        denom_id: impl Into<String>,
+        token_id: impl Into<String>,
+        from: &Addr,
+        to: &Addr,
+    ) -> CosmosMsg<M> {
+        self.transfer_nft(denom_id, token_id, from.as_str(), to.as_str())
+    }
+
+    pub fn send_nft_to<M: From<CudosMsg>>(
+        &self,
+        denom_id: impl Into<String>,
+        token_id: impl Into<String>,
+        from: &Addr,
+        contract: &Addr,
+        msg: Binary,
+    ) -> StdResult<Vec<CosmosMsg<M>>> {
+        self.send_nft(denom_id, token_id, from.as_str(), contract.as_str(), msg)
+    }
+
+    pub fn transfer_denom_to<M: From<CudosMsg>>(
+        &self,
+        denom_id: impl Into<String>,
+        to: &Addr,
+    ) -> CosmosMsg<M> {
+        self.transfer_denom(denom_id, to.as_str())
+    }
+
+    pub fn approve_nft_for<M: From<CudosMsg>>(
+        &self,
+        denom_id: impl Into<String>,
+        token_id: impl Into<String>,
+        approved_address: &Addr,
+    ) -> CosmosMsg<M> {
+        self.approve_nft(denom_id, token_id, approved_address.as_str())
+    }
+
+    pub fn approve_all_for<M: From<CudosMsg>>(
+        &self,
+        approved_operator: &Addr,
+        approved: bool,
+    ) -> CosmosMsg<M> {
+        self.approve_all(approved_operator.as_str(), approved)
+    }
+
+    pub fn revoke_approval_for<M: From<CudosMsg>>(
+        &self,
+        denom_id: impl Into<String>,
+        token_id: impl Into<String>,
+        address_to_revoke: &Addr,
+    ) -> CosmosMsg<M> {
+        self.revoke_approval(denom_id, token_id, address_to_revoke.as_str())
+    }
+
+    pub fn buy_nft_for<M: From<CudosMsg>>(&self, id: u64, recipient: &Addr) -> CosmosMsg<M> {
+        self.buy_nft(id, recipient.as_str())
+    }
+}
@@ -0,0 +1,519 @@
+// A JSON parser for the schemas and data checked by `data_schema`. serde_json parses numbers
+// with fractions or exponents into `f64`, and the CosmWasm VM rejects contracts containing
+// floating point instructions, so numbers are never converted to floats here: integers are
+// kept as `i128` and other numbers as they are written.
+
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt;
+
+// Same limit as serde_json, so that deeply nested input can not exhaust the stack.
+const MAX_DEPTH: usize = 128;
+
+pub(crate) type Map = BTreeMap<String, Value>;
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Value {
+    Null,
+    Bool(bool),
+    Number(Number),
+    String(String),
+    Array(Vec<Value>),
+    Object(Map),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Number {
+    Integer(i128),
+    // Numbers with a fraction or an exponent, and integers beyond `i128`, as written.
+    Other(String),
+}
+
+impl Number {
+    pub(crate) fn as_integer(&self) -> Option<i128> {
+        match self {
+            Number::Integer(n) => Some(*n),
+            Number::Other(_) => None,
+        }
+    }
+}
+
+impl Value {
+    pub(crate) fn is_null(&self) -> bool {
+        matches!(self, Value::Null)
+    }
+
+    pub(crate) fn is_boolean(&self) -> bool {
+        matches!(self, Value::Bool(_))
+    }
+
+    pub(crate) fn is_number(&self) -> bool {
+        matches!(self, Value::Number(_))
+    }
+
+    pub(crate) fn is_string(&self) -> bool {
+        matches!(self, Value::String(_))
+    }
+
+    pub(crate) fn is_array(&self) -> bool {
+        matches!(self, Value::Array(_))
+    }
+
+    pub(crate) fn is_object(&self) -> bool {
+        matches!(self, Value::Object(_))
+    }
+
+    pub(crate) fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_array(&self) -> Option<&Vec<Value>> {
+        match self {
+            Value::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_u64(&self) -> Option<u64> {
+        match self {
+            Value::Number(n) => n.as_integer().and_then(|n| u64::try_from(n).ok()),
+            _ => None,
+        }
+    }
+}
+
+// Writes the value as compact JSON, with the properties of objects sorted by name.
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Null => f.write_str("null"),
+            Value::Bool(b) => write!(f, "{}", b),
+            Value::Number(Number::Integer(n)) => write!(f, "{}", n),
+            Value::Number(Number::Other(n)) => f.write_str(n),
+            Value::String(s) => write_string(f, s),
+            Value::Array(items) => {
+                f.write_str("[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(",")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                f.write_str("]")
+            }
+            Value::Object(fields) => {
+                f.write_str("{")?;
+                for (i, (name, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(",")?;
+                    }
+                    write_string(f, name)?;
+                    write!(f, ":{}", value)?;
+                }
+                f.write_str("}")
+            }
+        }
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    f.write_str("\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+    f.write_str("\"")
+}
+
+/// Parses a JSON document. Duplicated properties keep the last value, like serde_json.
+pub(crate) fn parse(input: &str) -> Result<Value, String> {
+    let mut parser = Parser {
+        input,
+        pos: 0,
+        depth: 0,
+    };
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    if parser.pos != input.len() {
+        return Err(parser.error("trailing characters"));
+    }
+    Ok(value)
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, msg: &str) -> String {
+        format!("{} at position {}", msg, self.pos)
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.as_bytes().get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, literal: &str) -> Result<(), String> {
+        if self.input[self.pos..].starts_with(literal) {
+            self.pos += literal.len();
+            Ok(())
+        } else {
+            Err(self.error("expected value"))
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'n') => self.expect("null").map(|_| Value::Null),
+            Some(b't') => self.expect("true").map(|_| Value::Bool(true)),
+            Some(b'f') => self.expect("false").map(|_| Value::Bool(false)),
+            Some(b'"') => self.string().map(Value::String),
+            Some(b'-' | b'0'..=b'9') => self.number().map(Value::Number),
+            Some(b'[') => self.nested(Parser::array),
+            Some(b'{') => self.nested(Parser::object),
+            Some(_) => Err(self.error("expected value")),
+            None => Err(self.error("unexpected end of input")),
+        }
+    }
+
+    fn nested(&mut self, parse: fn(&mut Self) -> Result<Value, String>) -> Result<Value, String> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error("nested too deeply"));
+        }
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+        value
+    }
+
+    fn array(&mut self) -> Result<Value, String> {
+        self.pos += 1;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Ok(Value::Array(items));
+        }
+        loop {
+            items.push(self.value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(Value::Array(items));
+                }
+                _ => return Err(self.error("expected `,` or `]`")),
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, String> {
+        self.pos += 1;
+        let mut fields = Map::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(Value::Object(fields));
+        }
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some(b'"') {
+                return Err(self.error("expected property name"));
+            }
+            let name = self.string()?;
+            self.skip_whitespace();
+            if self.peek() != Some(b':') {
+                return Err(self.error("expected `:`"));
+            }
+            self.pos += 1;
+            let value = self.value()?;
+            fields.insert(name, value);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(Value::Object(fields));
+                }
+                _ => return Err(self.error("expected `,` or `}`")),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.pos += 1;
+        let mut s = String::new();
+        let mut start = self.pos;
+        loop {
+            match self.peek() {
+                None => return Err(self.error("unterminated string")),
+                Some(b'"') => {
+                    s.push_str(&self.input[start..self.pos]);
+                    self.pos += 1;
+                    return Ok(s);
+                }
+                Some(b'\\') => {
+                    s.push_str(&self.input[start..self.pos]);
+                    self.pos += 1;
+                    s.push(self.escape()?);
+                    start = self.pos;
+                }
+                Some(b) if b < 0x20 => return Err(self.error("control character in string")),
+                Some(_) => self.pos += 1,
+            }
+        }
+    }
+
+    fn escape(&mut self) -> Result<char, String> {
+        let c = match self.peek() {
+            Some(b'"') => '"',
+            Some(b'\\') => '\\',
+            Some(b'/') => '/',
+            Some(b'b') => '\u{8}',
+            Some(b'f') => '\u{c}',
+            Some(b'n') => '\n',
+            Some(b'r') => '\r',
+            Some(b't') => '\t',
+            Some(b'u') => {
+                self.pos += 1;
+                return self.unicode_escape();
+            }
+            _ => return Err(self.error("invalid escape")),
+        };
+        self.pos += 1;
+        Ok(c)
+    }
+
+    // Reads the digits of `\uXXXX`, joining the surrogate pairs of characters outside the
+    // basic multilingual plane.
+    fn unicode_escape(&mut self) -> Result<char, String> {
+        let high = self.hex4()?;
+        let code = match high {
+            0xd800..=0xdbff => {
+                if !self.input[self.pos..].starts_with("\\u") {
+                    return Err(self.error("unpaired surrogate"));
+                }
+                self.pos += 2;
+                let low = self.hex4()?;
+                if !(0xdc00..=0xdfff).contains(&low) {
+                    return Err(self.error("unpaired surrogate"));
+                }
+                0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00)
+            }
+            0xdc00..=0xdfff => return Err(self.error("unpaired surrogate")),
+            code => code,
+        };
+        char::from_u32(code).ok_or_else(|| self.error("invalid escape"))
+    }
+
+    fn hex4(&mut self) -> Result<u32, String> {
+        let digits = self
+            .input
+            .get(self.pos..self.pos + 4)
+            .filter(|digits| digits.bytes().all(|b| b.is_ascii_hexdigit()))
+            .ok_or_else(|| self.error("invalid escape"))?;
+        self.pos += 4;
+        u32::from_str_radix(digits, 16).map_err(|_| self.error("invalid escape"))
+    }
+
+    fn number(&mut self) -> Result<Number, String> {
+        let start = self.pos;
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+        match self.peek() {
+            Some(b'0') => self.pos += 1,
+            Some(b'1'..=b'9') => self.digits(),
+            _ => return Err(self.error("invalid number")),
+        }
+
+        let mut integer = true;
+        if self.peek() == Some(b'.') {
+            integer = false;
+            self.pos += 1;
+            self.required_digits()?;
+        }
+        if let Some(b'e' | b'E') = self.peek() {
+            integer = false;
+            self.pos += 1;
+            if let Some(b'+' | b'-') = self.peek() {
+                self.pos += 1;
+            }
+            self.required_digits()?;
+        }
+
+        let text = &self.input[start..self.pos];
+        Ok(match text.parse() {
+            Ok(n) if integer => Number::Integer(n),
+            _ => Number::Other(text.to_string()),
+        })
+    }
+
+    fn digits(&mut self) {
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    fn required_digits(&mut self) -> Result<(), String> {
+        if !matches!(self.peek(), Some(b'0'..=b'9')) {
+            return Err(self.error("invalid number"));
+        }
+        self.digits();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn object(fields: &[(&str, Value)]) -> Value {
+        Value::Object(
+            fields
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.clone()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn parses_every_kind_of_value() {
+        let value = parse(
+            r#" {"null": null, "bools": [true, false], "name": "kitten",
+                 "nested": {"empty": [], "also": {}}} "#,
+        )
+        .unwrap();
+        assert_eq!(
+            value,
+            object(&[
+                ("null", Value::Null),
+                (
+                    "bools",
+                    Value::Array(vec![Value::Bool(true), Value::Bool(false)])
+                ),
+                ("name", Value::String("kitten".to_string())),
+                (
+                    "nested",
+                    object(&[("empty", Value::Array(vec![])), ("also", object(&[]))])
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn keeps_numbers_out_of_floating_point() {
+        let number = |input| match parse(input).unwrap() {
+            Value::Number(n) => n,
+            value => panic!("{} is not a number", value),
+        };
+        assert_eq!(number("0"), Number::Integer(0));
+        assert_eq!(number("-0"), Number::Integer(0));
+        assert_eq!(number("-42"), Number::Integer(-42));
+        assert_eq!(
+            number("18446744073709551616"),
+            Number::Integer(18446744073709551616)
+        );
+        assert_eq!(number("1.50"), Number::Other("1.50".to_string()));
+        assert_eq!(number("1e3"), Number::Other("1e3".to_string()));
+        assert_eq!(number("-2.5E-3"), Number::Other("-2.5E-3".to_string()));
+        let huge = "1".repeat(40);
+        assert_eq!(number(&huge), Number::Other(huge.clone()));
+    }
+
+    #[test]
+    fn decodes_escapes() {
+        let value = parse(r#""a\"b\\c\/d\n\té😀""#).unwrap();
+        assert_eq!(value, Value::String("a\"b\\c/d\n\té😀".to_string()));
+        // Characters outside of escapes are kept as they are.
+        assert_eq!(parse(r#""é""#).unwrap(), Value::String("é".to_string()));
+    }
+
+    #[test]
+    fn keeps_the_last_duplicated_property() {
+        assert_eq!(
+            parse(r#"{"a": 1, "a": 2}"#).unwrap(),
+            object(&[("a", Value::Number(Number::Integer(2)))])
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_json() {
+        for input in [
+            "",
+            "nul",
+            "[1,]",
+            "[1 2]",
+            "{\"a\" 1}",
+            "{a: 1}",
+            "{\"a\": 1,}",
+            "\"unterminated",
+            "\"tab\tinside\"",
+            r#""\x""#,
+            r#""\ud83d""#,
+            r#""\ude00""#,
+            r#""\u12""#,
+            "01",
+            "1.",
+            "-",
+            ".5",
+            "1e",
+            "+1",
+            "NaN",
+            "1 2",
+        ] {
+            assert!(parse(input).is_err(), "{:?} should be rejected", input);
+        }
+        assert_eq!(
+            parse("[1, 2] x").unwrap_err(),
+            "trailing characters at position 7"
+        );
+    }
+
+    #[test]
+    fn limits_nesting() {
+        let nested = |depth| "[".repeat(depth) + &"]".repeat(depth);
+        assert!(parse(&nested(MAX_DEPTH)).is_ok());
+        assert_eq!(
+            parse(&nested(MAX_DEPTH + 1)).unwrap_err(),
+            "nested too deeply at position 128"
+        );
+    }
+
+    #[test]
+    fn displays_compact_json() {
+        let input = r#"{"b": [1, 1.5, "x\"y\n"], "a": {"c": null, "d": true}}"#;
+        let value = parse(input).unwrap();
+        let json = value.to_string();
+        assert_eq!(json, r#"{"a":{"c":null,"d":true},"b":[1,1.5,"x\"y\n"]}"#);
+        assert_eq!(parse(&json).unwrap(), value);
+        assert_eq!(
+            Value::String("\u{1}".to_string()).to_string(),
+            r#""\u0001""#
+        );
+    }
+}
@@ -1,22 +1,92 @@
+#[cfg(feature = "schema-validation")]
+extern crate alloc;
+
+mod batch;
+mod builder;
+mod capability;
+#[cfg(feature = "cw721")]
+mod cw721_compat;
+#[cfg(feature = "schema-validation")]
+mod data_schema;
+mod error;
+mod events;
+mod factory;
+#[cfg(feature = "schema-validation")]
+mod json;
 mod msg;
+mod pagination;
 mod querier;
 mod query;
+mod receiver;
+mod reply;
+mod typed;
+mod validation;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod testing;
 
+pub use batch::{
+    create_batch_burn_msgs, create_batch_mint_msgs, create_batch_transfer_msgs, BatchCursor,
+    MintItem, TransferItem,
+};
+pub use builder::{
+    ApproveAll, ApproveNft, BurnNft, EditNft, IssueDenom, MintNft, RevokeApproval, TransferDenom,
+    TransferNft,
+};
+pub use capability::CudosCapability;
+#[cfg(feature = "cw721")]
+pub use cw721_compat::NftExtension;
+#[cfg(feature = "schema-validation")]
+pub use data_schema::validate_data_schema;
+pub use error::{CudosError, NFT_CODESPACE};
+pub use events::{
+    parse_events, ApprovedAll, ApprovedNft, BurnedNft, CudosEvent, EditedNft, IssuedDenom,
+    MintedNft, RevokedNft, TransferredDenom, TransferredNft, ATTRIBUTE_KEY_APPROVED,
+    ATTRIBUTE_KEY_APPROVED_ADDRESS, ATTRIBUTE_KEY_CREATOR, ATTRIBUTE_KEY_DENOM_ID,
+    ATTRIBUTE_KEY_DENOM_NAME, ATTRIBUTE_KEY_FROM, ATTRIBUTE_KEY_OPERATOR, ATTRIBUTE_KEY_OWNER,
+    ATTRIBUTE_KEY_RECIPIENT, ATTRIBUTE_KEY_REVOKED_ADDRESS, ATTRIBUTE_KEY_SENDER, ATTRIBUTE_KEY_TO,
+    ATTRIBUTE_KEY_TOKEN_ID, ATTRIBUTE_KEY_TOKEN_URI, EVENT_TYPE_APPROVE_ALL,
+    EVENT_TYPE_APPROVE_NFT, EVENT_TYPE_BURN_NFT, EVENT_TYPE_EDIT_NFT, EVENT_TYPE_ISSUE_DENOM,
+    EVENT_TYPE_MINT_NFT, EVENT_TYPE_REVOKE_NFT, EVENT_TYPE_TRANSFER_DENOM, EVENT_TYPE_TRANSFER_NFT,
+};
+pub use factory::CudosMsgFactory;
 pub use msg::{
-    create_approve_all_msg, create_approve_nft_msg, create_burn_nft_msg, create_edit_nft_msg,
-    create_issue_denom_msg, create_mint_nft_msg, create_revoke_msg, create_transfer_nft_msg, 
-    create_transfer_denom_msg,
-    CudosMsg,
+    create_approve_all_msg, create_approve_nft_msg, create_burn_nft_msg, create_buy_nft_msg,
+    create_cancel_send_to_eth_msg, create_create_address_msg, create_delete_address_msg,
+    create_edit_nft_msg, create_edit_nft_msg_with, create_issue_denom_msg,
+    create_issue_denom_msg_with_metadata, create_marketplace_mint_nft_msg, create_mint_nft_msg,
+    create_mint_nft_msg_with, create_publish_collection_msg, create_publish_nft_msg,
+    create_revoke_msg, create_send_to_eth_msg, create_transfer_denom_msg,
+    create_transfer_nft_msg, create_unpublish_collection_msg, create_unpublish_nft_msg,
+    create_update_address_msg, create_update_price_msg, CudosMsg, DenomMetadata,
 };
-pub use querier::CudosQuerier;
+pub use pagination::PageIter;
+pub use querier::{CudosQuerier, CudosQueryExt};
 pub use query::{
-    Collection, CollectionResponse, CudosQuery, Denom, DenomResponse, DenomsResponse, IDCollection,
-    Owner, OwnerCollectionResponse, PageResponse, QueryApprovalsResponse,
-    QueryApprovedForAllResponse, QueryNFTResponse, SupplyResponse, NFT, PaginationRequest,
+    AddressResponse, AddressbookEntry, AnnualProvisionsResponse, BatchFees, BatchFeesResponse,
+    Collection, CollectionResponse, CudosQuery, Denom, DenomResponse, DenomToErc20Response,
+    DenomsResponse, Erc20ToDenomResponse, Erc20Token, IDCollection, LastObservedEthNonceResponse,
+    Listing, ListingResponse, ListingsResponse, MarketplaceCollection,
+    MarketplaceCollectionResponse, MarketplaceCollectionsResponse, MintParams, MintParamsResponse,
+    OutgoingTransferTx, OutgoingTxBatch, Owner, OwnerCollectionResponse, PageResponse,
+    PaginationRequest, PendingBatchesResponse, QueryApprovalsResponse, QueryApprovedForAllResponse,
+    QueryNFTResponse, Royalty, SupplyResponse, NFT,
+};
+pub use receiver::{create_send_nft_msgs, NftReceiveMsg, ReceiverExecuteMsg};
+pub use reply::{
+    parse_approve_all_reply, parse_approve_nft_reply, parse_burn_nft_reply, parse_edit_nft_reply,
+    parse_issue_denom_reply, parse_mint_nft_reply, parse_reply, parse_revoke_approval_reply,
+    parse_transfer_denom_reply, parse_transfer_nft_reply,
+};
+pub use typed::{TypedCollection, TypedNft};
+pub use validation::{
+    validate_addr, validate_denom_id, validate_denom_name, validate_eth_address, validate_symbol,
+    validate_token_id, validate_uri, ValidationError, MAX_DENOM_LEN, MAX_URI_LEN, MIN_DENOM_LEN,
 };
 
-// TODO: Research how to enable "cudos" on the blockchain
-// This export is added to all contracts that import this package, signifying that they require
-// "cudos" support on the chain they run on.
-// #[no_mangle]
-// extern "C" fn requires_cudos() {}
+// This export is added to all contracts that import this package with the `requires-cudos`
+// feature, signifying that they require "cudos" support on the chain they run on. Chains
+// without it reject the contract on upload instead of failing its first custom message.
+#[cfg(all(target_arch = "wasm32", feature = "requires-cudos"))]
+#[no_mangle]
+extern "C" fn requires_cudos() {}
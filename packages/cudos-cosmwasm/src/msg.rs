@@ -1,17 +1,20 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use cosmwasm_std::CosmosMsg;
+use cosmwasm_std::{to_vec, Coin, CosmosMsg, StdError, StdResult};
 
 use cosmwasm_std::CustomMsg;
 
+use crate::query::Royalty;
+
 // implement custom msg
 impl CustomMsg for CudosMsg {}
 
-// this is a helper to be able to return these as CosmosMsg easier
-impl From<CudosMsg> for CosmosMsg<CudosMsg> {
+// this is a helper to be able to return these as CosmosMsg easier. Contracts whose custom
+// message wraps `CudosMsg` in a larger enum get a `CosmosMsg` of their own type.
+impl<M: From<CudosMsg>> From<CudosMsg> for CosmosMsg<M> {
     fn from(original: CudosMsg) -> Self {
-        CosmosMsg::Custom(original)
+        CosmosMsg::Custom(M::from(original))
     }
 }
 
@@ -23,6 +26,20 @@ pub enum CudosMsg {
         name: String,
         symbol: String,
         schema: Option<String>,
+        // Optional denom metadata. Omitted from the message when unset, so nodes that do not
+        // know these fields still accept it.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        uri: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        uri_hash: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        data: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        traits: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        minter: Option<String>,
         sender: String,
         contract_address_signer: String,
     },
@@ -84,28 +101,151 @@ pub enum CudosMsg {
         sender: String,
         contract_address_signer: String,
     },
+    // Messages of the marketplace module. Listings and marketplace collections are referred
+    // to by the ids the module assigns when they are published.
+    PublishCollectionMsg {
+        denom_id: String,
+        mint_royalties: Vec<Royalty>,
+        resale_royalties: Vec<Royalty>,
+        sender: String,
+        contract_address_signer: String,
+    },
+    UnpublishCollectionMsg {
+        id: u64,
+        sender: String,
+        contract_address_signer: String,
+    },
+    PublishNftMsg {
+        denom_id: String,
+        token_id: String,
+        price: Coin,
+        sender: String,
+        contract_address_signer: String,
+    },
+    UnpublishNftMsg {
+        id: u64,
+        sender: String,
+        contract_address_signer: String,
+    },
+    BuyNftMsg {
+        id: u64,
+        recipient: String,
+        sender: String,
+        contract_address_signer: String,
+    },
+    UpdatePriceMsg {
+        id: u64,
+        price: Coin,
+        sender: String,
+        contract_address_signer: String,
+    },
+    MarketplaceMintNftMsg {
+        denom_id: String,
+        name: String,
+        uri: Option<String>,
+        data: Option<String>,
+        uid: Option<String>,
+        price: Coin,
+        recipient: String,
+        sender: String,
+        contract_address_signer: String,
+    },
+    // Messages of the addressbook module. An entry is identified by its creator, the `sender`,
+    // together with `network` and `label`.
+    CreateAddressMsg {
+        network: String,
+        label: String,
+        value: String,
+        sender: String,
+        contract_address_signer: String,
+    },
+    UpdateAddressMsg {
+        network: String,
+        label: String,
+        value: String,
+        sender: String,
+        contract_address_signer: String,
+    },
+    DeleteAddressMsg {
+        network: String,
+        label: String,
+        sender: String,
+        contract_address_signer: String,
+    },
+    // Messages of the gravity bridge module.
+    SendToEthMsg {
+        eth_dest: String,
+        amount: Coin,
+        bridge_fee: Coin,
+        sender: String,
+        contract_address_signer: String,
+    },
+    CancelSendToEthMsg {
+        transaction_id: u64,
+        sender: String,
+        contract_address_signer: String,
+    },
+}
+
+/// The optional metadata of a denom issued with `create_issue_denom_msg_with_metadata`. Unset
+/// fields are left out of the message.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DenomMetadata {
+    pub description: Option<String>,
+    pub uri: Option<String>,
+    pub uri_hash: Option<String>,
+    pub data: Option<String>,
+    pub traits: Option<String>,
+    pub minter: Option<String>,
 }
 
-pub fn create_issue_denom_msg(
+pub fn create_issue_denom_msg<M: From<CudosMsg>>(
     id: String,
     name: String,
     symbol: String,
     schema: Option<String>,
     sender: String,
     contract_address_signer: String,
-) -> CosmosMsg<CudosMsg> {
+) -> CosmosMsg<M> {
+    create_issue_denom_msg_with_metadata(
+        id,
+        name,
+        symbol,
+        schema,
+        DenomMetadata::default(),
+        sender,
+        contract_address_signer,
+    )
+}
+
+/// Same as `create_issue_denom_msg`, also setting the metadata of the denom.
+pub fn create_issue_denom_msg_with_metadata<M: From<CudosMsg>>(
+    id: String,
+    name: String,
+    symbol: String,
+    schema: Option<String>,
+    metadata: DenomMetadata,
+    sender: String,
+    contract_address_signer: String,
+) -> CosmosMsg<M> {
     CudosMsg::IssueDenomMsg {
         id,
         name,
         symbol,
         schema,
+        description: metadata.description,
+        uri: metadata.uri,
+        uri_hash: metadata.uri_hash,
+        data: metadata.data,
+        traits: metadata.traits,
+        minter: metadata.minter,
         sender,
         contract_address_signer,
     }
     .into()
 }
 
-pub fn create_mint_nft_msg(
+pub fn create_mint_nft_msg<M: From<CudosMsg>>(
     denom_id: String,
     name: String,
     uri: Option<String>,
@@ -113,7 +253,7 @@ pub fn create_mint_nft_msg(
     recipient: String,
     sender: String,
     contract_address_signer: String,
-) -> CosmosMsg<CudosMsg> {
+) -> CosmosMsg<M> {
     CudosMsg::MintNftMsg {
         denom_id,
         name,
@@ -126,7 +266,28 @@ pub fn create_mint_nft_msg(
     .into()
 }
 
-pub fn create_edit_nft_msg(
+/// Same as `create_mint_nft_msg`, with `data` serialized to JSON.
+pub fn create_mint_nft_msg_with<T: Serialize, M: From<CudosMsg>>(
+    denom_id: String,
+    name: String,
+    uri: Option<String>,
+    data: Option<&T>,
+    recipient: String,
+    sender: String,
+    contract_address_signer: String,
+) -> StdResult<CosmosMsg<M>> {
+    Ok(create_mint_nft_msg(
+        denom_id,
+        name,
+        uri,
+        data.map(encode_data).transpose()?,
+        recipient,
+        sender,
+        contract_address_signer,
+    ))
+}
+
+pub fn create_edit_nft_msg<M: From<CudosMsg>>(
     denom_id: String,
     token_id: String,
     name: Option<String>,
@@ -134,7 +295,7 @@ pub fn create_edit_nft_msg(
     data: Option<String>,
     sender: String,
     contract_address_signer: String,
-) -> CosmosMsg<CudosMsg> {
+) -> CosmosMsg<M> {
     CudosMsg::EditNftMsg {
         token_id,
         denom_id,
@@ -147,12 +308,33 @@ pub fn create_edit_nft_msg(
     .into()
 }
 
-pub fn create_burn_nft_msg(
+/// Same as `create_edit_nft_msg`, with `data` serialized to JSON.
+pub fn create_edit_nft_msg_with<T: Serialize, M: From<CudosMsg>>(
     denom_id: String,
     token_id: String,
+    name: Option<String>,
+    uri: Option<String>,
+    data: Option<&T>,
     sender: String,
     contract_address_signer: String,
-) -> CosmosMsg<CudosMsg> {
+) -> StdResult<CosmosMsg<M>> {
+    Ok(create_edit_nft_msg(
+        denom_id,
+        token_id,
+        name,
+        uri,
+        data.map(encode_data).transpose()?,
+        sender,
+        contract_address_signer,
+    ))
+}
+
+pub fn create_burn_nft_msg<M: From<CudosMsg>>(
+    denom_id: String,
+    token_id: String,
+    sender: String,
+    contract_address_signer: String,
+) -> CosmosMsg<M> {
     CudosMsg::BurnNftMsg {
         token_id,
         denom_id,
@@ -162,14 +344,14 @@ pub fn create_burn_nft_msg(
     .into()
 }
 
-pub fn create_transfer_nft_msg(
+pub fn create_transfer_nft_msg<M: From<CudosMsg>>(
     denom_id: String,
     token_id: String,
     from: String,
     to: String,
     sender: String,
     contract_address_signer: String,
-) -> CosmosMsg<CudosMsg> {
+) -> CosmosMsg<M> {
     CudosMsg::TransferNftMsg {
         denom_id,
         token_id,
@@ -181,12 +363,12 @@ pub fn create_transfer_nft_msg(
     .into()
 }
 
-pub fn create_transfer_denom_msg(
+pub fn create_transfer_denom_msg<M: From<CudosMsg>>(
     denom_id: String,
     to: String,
     sender: String,
     contract_address_signer: String,
-) -> CosmosMsg<CudosMsg> {
+) -> CosmosMsg<M> {
     CudosMsg::TransferDenomMsg {
         denom_id,
         to,
@@ -196,13 +378,13 @@ pub fn create_transfer_denom_msg(
     .into()
 }
 
-pub fn create_approve_nft_msg(
+pub fn create_approve_nft_msg<M: From<CudosMsg>>(
     denom_id: String,
     token_id: String,
     approved_address: String,
     sender: String,
     contract_address_signer: String,
-) -> CosmosMsg<CudosMsg> {
+) -> CosmosMsg<M> {
     CudosMsg::ApproveNftMsg {
         token_id,
         denom_id,
@@ -213,12 +395,12 @@ pub fn create_approve_nft_msg(
     .into()
 }
 
-pub fn create_approve_all_msg(
+pub fn create_approve_all_msg<M: From<CudosMsg>>(
     approved_operator: String,
     approved: bool,
     sender: String,
     contract_address_signer: String,
-) -> CosmosMsg<CudosMsg> {
+) -> CosmosMsg<M> {
     CudosMsg::ApproveAllMsg {
         approved_operator,
         approved,
@@ -228,13 +410,13 @@ pub fn create_approve_all_msg(
     .into()
 }
 
-pub fn create_revoke_msg(
+pub fn create_revoke_msg<M: From<CudosMsg>>(
     denom_id: String,
     token_id: String,
     address_to_revoke: String,
     sender: String,
     contract_address_signer: String,
-) -> CosmosMsg<CudosMsg> {
+) -> CosmosMsg<M> {
     CudosMsg::RevokeApprovalMsg {
         denom_id,
         token_id,
@@ -244,3 +426,474 @@ pub fn create_revoke_msg(
     }
     .into()
 }
+
+/// Publishes the collection of `denom_id` on the marketplace. The royalties are paid to their
+/// addresses from the price of every NFT minted through the marketplace or resold on it.
+pub fn create_publish_collection_msg<M: From<CudosMsg>>(
+    denom_id: String,
+    mint_royalties: Vec<Royalty>,
+    resale_royalties: Vec<Royalty>,
+    sender: String,
+    contract_address_signer: String,
+) -> CosmosMsg<M> {
+    CudosMsg::PublishCollectionMsg {
+        denom_id,
+        mint_royalties,
+        resale_royalties,
+        sender,
+        contract_address_signer,
+    }
+    .into()
+}
+
+pub fn create_unpublish_collection_msg<M: From<CudosMsg>>(
+    id: u64,
+    sender: String,
+    contract_address_signer: String,
+) -> CosmosMsg<M> {
+    CudosMsg::UnpublishCollectionMsg {
+        id,
+        sender,
+        contract_address_signer,
+    }
+    .into()
+}
+
+/// Lists the NFT for sale at `price`. The NFT stays with its owner until it is bought.
+pub fn create_publish_nft_msg<M: From<CudosMsg>>(
+    denom_id: String,
+    token_id: String,
+    price: Coin,
+    sender: String,
+    contract_address_signer: String,
+) -> CosmosMsg<M> {
+    CudosMsg::PublishNftMsg {
+        denom_id,
+        token_id,
+        price,
+        sender,
+        contract_address_signer,
+    }
+    .into()
+}
+
+pub fn create_unpublish_nft_msg<M: From<CudosMsg>>(
+    id: u64,
+    sender: String,
+    contract_address_signer: String,
+) -> CosmosMsg<M> {
+    CudosMsg::UnpublishNftMsg {
+        id,
+        sender,
+        contract_address_signer,
+    }
+    .into()
+}
+
+/// Buys the listing `id` for `recipient`. The price is paid from the balance of `sender`.
+pub fn create_buy_nft_msg<M: From<CudosMsg>>(
+    id: u64,
+    recipient: String,
+    sender: String,
+    contract_address_signer: String,
+) -> CosmosMsg<M> {
+    CudosMsg::BuyNftMsg {
+        id,
+        recipient,
+        sender,
+        contract_address_signer,
+    }
+    .into()
+}
+
+pub fn create_update_price_msg<M: From<CudosMsg>>(
+    id: u64,
+    price: Coin,
+    sender: String,
+    contract_address_signer: String,
+) -> CosmosMsg<M> {
+    CudosMsg::UpdatePriceMsg {
+        id,
+        price,
+        sender,
+        contract_address_signer,
+    }
+    .into()
+}
+
+/// Mints an NFT of a published collection to `recipient`. `sender` pays `price`, which is split
+/// between the mint royalties of the collection. `uid` is an optional id the module keeps unique
+/// within the collection.
+#[allow(clippy::too_many_arguments)]
+pub fn create_marketplace_mint_nft_msg<M: From<CudosMsg>>(
+    denom_id: String,
+    name: String,
+    uri: Option<String>,
+    data: Option<String>,
+    uid: Option<String>,
+    price: Coin,
+    recipient: String,
+    sender: String,
+    contract_address_signer: String,
+) -> CosmosMsg<M> {
+    CudosMsg::MarketplaceMintNftMsg {
+        denom_id,
+        name,
+        uri,
+        data,
+        uid,
+        price,
+        recipient,
+        sender,
+        contract_address_signer,
+    }
+    .into()
+}
+
+/// Stores `value`, an address on `network`, under `label` in the addressbook of `sender`.
+pub fn create_create_address_msg<M: From<CudosMsg>>(
+    network: String,
+    label: String,
+    value: String,
+    sender: String,
+    contract_address_signer: String,
+) -> CosmosMsg<M> {
+    CudosMsg::CreateAddressMsg {
+        network,
+        label,
+        value,
+        sender,
+        contract_address_signer,
+    }
+    .into()
+}
+
+pub fn create_update_address_msg<M: From<CudosMsg>>(
+    network: String,
+    label: String,
+    value: String,
+    sender: String,
+    contract_address_signer: String,
+) -> CosmosMsg<M> {
+    CudosMsg::UpdateAddressMsg {
+        network,
+        label,
+        value,
+        sender,
+        contract_address_signer,
+    }
+    .into()
+}
+
+pub fn create_delete_address_msg<M: From<CudosMsg>>(
+    network: String,
+    label: String,
+    sender: String,
+    contract_address_signer: String,
+) -> CosmosMsg<M> {
+    CudosMsg::DeleteAddressMsg {
+        network,
+        label,
+        sender,
+        contract_address_signer,
+    }
+    .into()
+}
+
+/// Sends `amount` from `sender` to `eth_dest` on Ethereum. The transfer waits in the pool of
+/// the bridge until it is included in a batch, and `bridge_fee` is paid to the relayer of the
+/// batch.
+pub fn create_send_to_eth_msg<M: From<CudosMsg>>(
+    eth_dest: String,
+    amount: Coin,
+    bridge_fee: Coin,
+    sender: String,
+    contract_address_signer: String,
+) -> CosmosMsg<M> {
+    CudosMsg::SendToEthMsg {
+        eth_dest,
+        amount,
+        bridge_fee,
+        sender,
+        contract_address_signer,
+    }
+    .into()
+}
+
+/// Cancels a transfer that is not batched yet and refunds its amount and fee to `sender`.
+pub fn create_cancel_send_to_eth_msg<M: From<CudosMsg>>(
+    transaction_id: u64,
+    sender: String,
+    contract_address_signer: String,
+) -> CosmosMsg<M> {
+    CudosMsg::CancelSendToEthMsg {
+        transaction_id,
+        sender,
+        contract_address_signer,
+    }
+    .into()
+}
+
+fn encode_data<T: Serialize>(data: &T) -> StdResult<String> {
+    String::from_utf8(to_vec(data)?).map_err(StdError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::str::FromStr;
+
+    use cosmwasm_std::{from_slice, Decimal};
+
+    #[derive(Serialize)]
+    struct Meta {
+        level: u32,
+    }
+
+    #[test]
+    fn data_type_can_be_named_without_data() {
+        let msg = create_mint_nft_msg_with::<Meta, CudosMsg>(
+            "denom".to_string(),
+            "name".to_string(),
+            None,
+            None,
+            "recipient".to_string(),
+            "sender".to_string(),
+            "signer".to_string(),
+        )
+        .unwrap();
+        assert_eq!(
+            msg,
+            create_mint_nft_msg(
+                "denom".to_string(),
+                "name".to_string(),
+                None,
+                None,
+                "recipient".to_string(),
+                "sender".to_string(),
+                "signer".to_string(),
+            )
+        );
+
+        let msg = create_edit_nft_msg_with::<Meta, CudosMsg>(
+            "denom".to_string(),
+            "1".to_string(),
+            Some("name".to_string()),
+            None,
+            None,
+            "sender".to_string(),
+            "signer".to_string(),
+        )
+        .unwrap();
+        assert!(matches!(
+            msg,
+            CosmosMsg::Custom(CudosMsg::EditNftMsg { data: None, .. })
+        ));
+    }
+
+    #[test]
+    fn data_is_serialized_to_json() {
+        let msg: CosmosMsg<CudosMsg> = create_mint_nft_msg_with(
+            "denom".to_string(),
+            "name".to_string(),
+            None,
+            Some(&Meta { level: 3 }),
+            "recipient".to_string(),
+            "sender".to_string(),
+            "signer".to_string(),
+        )
+        .unwrap();
+        match msg {
+            CosmosMsg::Custom(CudosMsg::MintNftMsg { data, .. }) => {
+                assert_eq!(data.as_deref(), Some(r#"{"level":3}"#))
+            }
+            msg => panic!("unexpected message {:?}", msg),
+        }
+    }
+
+    // Checks that `msg` is sent as `json` and parsed back from it.
+    fn assert_json(msg: CudosMsg, json: &str) {
+        assert_eq!(String::from_utf8(to_vec(&msg).unwrap()).unwrap(), json);
+        assert_eq!(from_slice::<CudosMsg>(json.as_bytes()).unwrap(), msg);
+    }
+
+    #[test]
+    fn marketplace_messages() {
+        let royalty = Royalty {
+            address: "artist".to_string(),
+            percent: Decimal::from_str("2.5").unwrap(),
+        };
+        assert_json(
+            CudosMsg::PublishCollectionMsg {
+                denom_id: "kittens".to_string(),
+                mint_royalties: vec![royalty.clone()],
+                resale_royalties: vec![],
+                sender: "sender".to_string(),
+                contract_address_signer: "signer".to_string(),
+            },
+            r#"{"publish_collection_msg":{"denom_id":"kittens","mint_royalties":[{"address":"artist","percent":"2.5"}],"resale_royalties":[],"sender":"sender","contract_address_signer":"signer"}}"#,
+        );
+        assert_json(
+            CudosMsg::UpdatePriceMsg {
+                id: 7,
+                price: Coin::new(1_000_000_000_000_000_000_000, "acudos"),
+                sender: "sender".to_string(),
+                contract_address_signer: "signer".to_string(),
+            },
+            r#"{"update_price_msg":{"id":7,"price":{"denom":"acudos","amount":"1000000000000000000000"},"sender":"sender","contract_address_signer":"signer"}}"#,
+        );
+
+        // The node formats decimals with 18 digits.
+        let msg: CudosMsg = from_slice(
+            br#"{"publish_collection_msg":{"denom_id":"kittens","mint_royalties":[{"address":"artist","percent":"2.500000000000000000"}],"resale_royalties":[],"sender":"sender","contract_address_signer":"signer"}}"#,
+        )
+        .unwrap();
+        assert!(
+            matches!(msg, CudosMsg::PublishCollectionMsg { mint_royalties, .. } if mint_royalties == [royalty])
+        );
+    }
+
+    #[test]
+    fn addressbook_messages() {
+        assert_json(
+            CudosMsg::CreateAddressMsg {
+                network: "ETH".to_string(),
+                label: "1@payouts".to_string(),
+                value: "0x52908400098527886E0F7030069857D2E4169EE7".to_string(),
+                sender: "sender".to_string(),
+                contract_address_signer: "signer".to_string(),
+            },
+            r#"{"create_address_msg":{"network":"ETH","label":"1@payouts","value":"0x52908400098527886E0F7030069857D2E4169EE7","sender":"sender","contract_address_signer":"signer"}}"#,
+        );
+        assert_json(
+            CudosMsg::DeleteAddressMsg {
+                network: "ETH".to_string(),
+                label: "1@payouts".to_string(),
+                sender: "sender".to_string(),
+                contract_address_signer: "signer".to_string(),
+            },
+            r#"{"delete_address_msg":{"network":"ETH","label":"1@payouts","sender":"sender","contract_address_signer":"signer"}}"#,
+        );
+    }
+
+    #[test]
+    fn gravity_messages() {
+        assert_json(
+            CudosMsg::SendToEthMsg {
+                eth_dest: "0x52908400098527886E0F7030069857D2E4169EE7".to_string(),
+                amount: Coin::new(1_000_000_000_000_000_000_000, "acudos"),
+                bridge_fee: Coin::new(5_000_000_000_000_000_000, "acudos"),
+                sender: "sender".to_string(),
+                contract_address_signer: "signer".to_string(),
+            },
+            r#"{"send_to_eth_msg":{"eth_dest":"0x52908400098527886E0F7030069857D2E4169EE7","amount":{"denom":"acudos","amount":"1000000000000000000000"},"bridge_fee":{"denom":"acudos","amount":"5000000000000000000"},"sender":"sender","contract_address_signer":"signer"}}"#,
+        );
+        assert_json(
+            CudosMsg::CancelSendToEthMsg {
+                transaction_id: 12,
+                sender: "sender".to_string(),
+                contract_address_signer: "signer".to_string(),
+            },
+            r#"{"cancel_send_to_eth_msg":{"transaction_id":12,"sender":"sender","contract_address_signer":"signer"}}"#,
+        );
+    }
+
+    fn issue_denom_json(metadata: DenomMetadata) -> String {
+        let msg: CosmosMsg<CudosMsg> = create_issue_denom_msg_with_metadata(
+            "kittens".to_string(),
+            "Kittens".to_string(),
+            "KIT".to_string(),
+            None,
+            metadata,
+            "sender".to_string(),
+            "signer".to_string(),
+        );
+        match msg {
+            CosmosMsg::Custom(msg) => String::from_utf8(to_vec(&msg).unwrap()).unwrap(),
+            msg => panic!("unexpected message {:?}", msg),
+        }
+    }
+
+    #[test]
+    fn unset_denom_metadata_is_omitted() {
+        assert_eq!(
+            issue_denom_json(DenomMetadata::default()),
+            r#"{"issue_denom_msg":{"id":"kittens","name":"Kittens","symbol":"KIT","schema":null,"sender":"sender","contract_address_signer":"signer"}}"#
+        );
+
+        let json = issue_denom_json(DenomMetadata {
+            description: Some("cute".to_string()),
+            ..DenomMetadata::default()
+        });
+        assert!(json.contains(r#""description":"cute""#));
+        assert!(!json.contains("uri"));
+        assert!(!json.contains("minter"));
+    }
+
+    #[test]
+    fn set_denom_metadata_round_trips() {
+        let metadata = DenomMetadata {
+            description: Some("cute".to_string()),
+            uri: Some("https://kittens.example".to_string()),
+            uri_hash: Some("f00d".to_string()),
+            data: Some(r#"{"legs":4}"#.to_string()),
+            traits: Some("transferable".to_string()),
+            minter: Some("minter".to_string()),
+        };
+        let json = issue_denom_json(metadata.clone());
+        match from_slice(json.as_bytes()).unwrap() {
+            CudosMsg::IssueDenomMsg {
+                description,
+                uri,
+                uri_hash,
+                data,
+                traits,
+                minter,
+                ..
+            } => assert_eq!(
+                DenomMetadata {
+                    description,
+                    uri,
+                    uri_hash,
+                    data,
+                    traits,
+                    minter,
+                },
+                metadata
+            ),
+            msg => panic!("unexpected message {:?}", msg),
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum MyMsg {
+        Cudos(CudosMsg),
+    }
+
+    impl From<CudosMsg> for MyMsg {
+        fn from(msg: CudosMsg) -> Self {
+            MyMsg::Cudos(msg)
+        }
+    }
+
+    #[test]
+    fn data_can_be_sent_in_a_wrapping_message() {
+        let msg: CosmosMsg<MyMsg> = create_edit_nft_msg_with(
+            "denom".to_string(),
+            "1".to_string(),
+            None,
+            None,
+            Some(&Meta { level: 4 }),
+            "sender".to_string(),
+            "signer".to_string(),
+        )
+        .unwrap();
+        match msg {
+            CosmosMsg::Custom(MyMsg::Cudos(CudosMsg::EditNftMsg { data, .. })) => {
+                assert_eq!(data.as_deref(), Some(r#"{"level":4}"#))
+            }
+            msg => panic!("unexpected message {:?}", msg),
+        }
+    }
+}
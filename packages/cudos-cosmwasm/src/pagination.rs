@@ -0,0 +1,251 @@
+use std::collections::VecDeque;
+
+use cosmwasm_std::StdResult;
+
+use crate::query::PaginationRequest;
+
+type FetchPage<'a, T> = Box<dyn Fn(PaginationRequest) -> StdResult<(Vec<T>, Option<String>)> + 'a>;
+
+/// Iterator that lazily follows `PageResponse.next_key`, querying one page at a time.
+///
+/// Use `max_items` to bound the number of items (and therefore queries) a call can consume.
+pub struct PageIter<'a, T> {
+    fetch: FetchPage<'a, T>,
+    page_size: u64,
+    max_items: Option<u64>,
+    yielded: u64,
+    buffer: VecDeque<T>,
+    next_key: Option<String>,
+    done: bool,
+}
+
+impl<'a, T> PageIter<'a, T> {
+    pub(crate) fn new<F>(page_size: u64, fetch: F) -> Self
+    where
+        F: Fn(PaginationRequest) -> StdResult<(Vec<T>, Option<String>)> + 'a,
+    {
+        PageIter {
+            fetch: Box::new(fetch),
+            page_size: page_size.max(1),
+            max_items: None,
+            yielded: 0,
+            buffer: VecDeque::new(),
+            next_key: None,
+            done: false,
+        }
+    }
+
+    /// Stops after `max_items` items. The last page is only as large as needed.
+    pub fn max_items(mut self, max_items: u64) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    fn remaining(&self) -> Option<u64> {
+        self.max_items.map(|max| max.saturating_sub(self.yielded))
+    }
+}
+
+impl<'a, T> Iterator for PageIter<'a, T> {
+    type Item = StdResult<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining() == Some(0) {
+            return None;
+        }
+
+        if self.buffer.is_empty() && !self.done {
+            let limit = match self.remaining() {
+                Some(remaining) => remaining.min(self.page_size),
+                None => self.page_size,
+            };
+            let request = PaginationRequest {
+                key: self.next_key.take(),
+                offset: None,
+                limit: Some(limit),
+                count_total: None,
+                reverse: None,
+            };
+
+            match (self.fetch)(request) {
+                Ok((items, next_key)) => {
+                    self.done = items.is_empty();
+                    self.next_key = next_key.filter(|key| !key.is_empty());
+                    if self.next_key.is_none() {
+                        self.done = true;
+                    }
+                    self.buffer.extend(items);
+                }
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(err));
+                }
+            }
+        }
+
+        let item = self.buffer.pop_front()?;
+        self.yielded += 1;
+        Some(Ok(item))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::cell::RefCell;
+
+    use cosmwasm_std::{from_binary, StdError};
+
+    use crate::querier::CudosQuerier;
+    use crate::query::{CudosQuery, DenomsResponse};
+    use crate::testing::{mock_cudos_dependencies, MockNftModule};
+
+    fn module_with_denoms(count: usize) -> MockNftModule {
+        let mut module = MockNftModule::new();
+        for i in 0..count {
+            let id = format!("denom{}", i);
+            module
+                .issue_denom(id.clone(), id.clone(), id, None, "creator")
+                .unwrap();
+        }
+        module
+    }
+
+    // Pages through the denoms of `module`, recording every request.
+    fn denoms<'a>(
+        module: &'a MockNftModule,
+        requests: &'a RefCell<Vec<PaginationRequest>>,
+        page_size: u64,
+    ) -> PageIter<'a, String> {
+        PageIter::new(page_size, move |pagination| {
+            requests.borrow_mut().push(pagination.clone());
+            let res: DenomsResponse = from_binary(&module.query(&CudosQuery::QueryDenoms {
+                pagination: Some(pagination),
+            })?)?;
+            Ok((
+                res.denoms
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|denom| denom.id)
+                    .collect(),
+                res.pagination.and_then(|p| p.next_key),
+            ))
+        })
+    }
+
+    #[test]
+    fn follows_next_key_across_pages() {
+        let module = module_with_denoms(5);
+        let requests = RefCell::new(vec![]);
+        let ids = denoms(&module, &requests, 2)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(
+            ids,
+            ["denom0", "denom1", "denom2", "denom3", "denom4"].map(String::from)
+        );
+
+        let requests = requests.into_inner();
+        assert_eq!(requests.len(), 3);
+        assert!(requests.iter().all(|r| r.limit == Some(2)));
+        assert_eq!(requests[0].key, None);
+        // Each page starts at the key handed back by the previous one.
+        for pair in requests.windows(2) {
+            let res: DenomsResponse = from_binary(
+                &module
+                    .query(&CudosQuery::QueryDenoms {
+                        pagination: Some(pair[0].clone()),
+                    })
+                    .unwrap(),
+            )
+            .unwrap();
+            assert_eq!(pair[1].key, res.pagination.unwrap().next_key);
+        }
+    }
+
+    #[test]
+    fn stops_without_next_key() {
+        for next_key in [None, Some(String::new())] {
+            let calls = RefCell::new(0);
+            let iter = PageIter::new(2, |_| {
+                *calls.borrow_mut() += 1;
+                Ok((vec![1, 2], next_key.clone()))
+            });
+            assert_eq!(iter.collect::<StdResult<Vec<_>>>().unwrap(), [1, 2]);
+            assert_eq!(*calls.borrow(), 1);
+        }
+    }
+
+    #[test]
+    fn stops_on_an_empty_page() {
+        let calls = RefCell::new(0);
+        let iter = PageIter::<u32>::new(2, |_| {
+            *calls.borrow_mut() += 1;
+            Ok((vec![], Some("a2V5".to_string())))
+        });
+        assert_eq!(iter.count(), 0);
+        assert_eq!(*calls.borrow(), 1);
+    }
+
+    #[test]
+    fn max_items_caps_the_items_and_the_last_page() {
+        let module = module_with_denoms(5);
+        let requests = RefCell::new(vec![]);
+        let ids = denoms(&module, &requests, 2)
+            .max_items(3)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(ids, ["denom0", "denom1", "denom2"].map(String::from));
+
+        let limits: Vec<_> = requests.into_inner().iter().map(|r| r.limit).collect();
+        assert_eq!(limits, [Some(2), Some(1)]);
+    }
+
+    #[test]
+    fn stops_after_an_error() {
+        let calls = RefCell::new(0);
+        let mut iter = PageIter::<u32>::new(2, |_| {
+            *calls.borrow_mut() += 1;
+            Err(StdError::generic_err("query failed"))
+        });
+        assert!(iter.next().unwrap().is_err());
+        assert!(iter.next().is_none());
+        assert_eq!(*calls.borrow(), 1);
+    }
+
+    #[test]
+    fn iterators_of_the_querier_page_through_the_mock() {
+        let mut deps = mock_cudos_dependencies();
+        deps.querier.nft = module_with_denoms(3);
+        for name in ["a", "b", "c", "d", "e"] {
+            deps.querier
+                .nft
+                .mint_nft("denom0", name, None, None, "owner")
+                .unwrap();
+        }
+        let deps = deps.as_ref();
+        let querier = CudosQuerier::new(&deps.querier);
+
+        let denoms = querier
+            .denoms_iter(2)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(denoms.len(), 3);
+
+        let names = querier
+            .collection_nfts_iter("denom0", 2)
+            .map(|nft| nft.map(|nft| nft.name.unwrap_or_default()))
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(names, ["a", "b", "c", "d", "e"]);
+
+        let tokens = querier
+            .owner_tokens_iter("owner", None, 2)
+            .max_items(4)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(tokens.len(), 4);
+        assert!(tokens.iter().all(|(denom_id, _)| denom_id == "denom0"));
+    }
+}
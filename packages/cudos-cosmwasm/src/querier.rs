@@ -1,72 +1,125 @@
-use cosmwasm_std::{QuerierWrapper, StdResult};
+use cosmwasm_std::{
+    from_binary, to_vec, Binary, ContractResult, CustomQuery, QuerierResult, QuerierWrapper,
+    QueryRequest, StdError, StdResult, SystemError, SystemResult,
+};
+use serde::de::DeserializeOwned;
 
+use crate::error::CudosError;
+use crate::pagination::PageIter;
 use crate::query::{
-    CollectionResponse, CudosQuery, DenomResponse, DenomsResponse, OwnerCollectionResponse,
-    QueryNFTResponse, SupplyResponse, QueryApprovalsResponse, QueryApprovedForAllResponse, PaginationRequest,
+    AddressResponse, AnnualProvisionsResponse, BatchFeesResponse, CollectionResponse, CudosQuery,
+    Denom, DenomResponse, DenomToErc20Response, DenomsResponse, Erc20ToDenomResponse,
+    LastObservedEthNonceResponse, Listing, ListingResponse, ListingsResponse,
+    MarketplaceCollection, MarketplaceCollectionResponse, MarketplaceCollectionsResponse,
+    MintParamsResponse, OwnerCollectionResponse, PaginationRequest, PendingBatchesResponse,
+    QueryApprovalsResponse, QueryApprovedForAllResponse, QueryNFTResponse, SupplyResponse, NFT,
+};
+use crate::validation::{
+    validate_denom_id, validate_denom_name, validate_symbol, validate_token_id,
 };
 
+/// Sends `CudosQuery` requests through a querier, also when its custom query type wraps
+/// `CudosQuery` in a larger enum to combine the Cudos bindings with others.
+pub trait CudosQueryExt {
+    /// Sends `request` as the custom query of the querier and returns the unparsed result.
+    fn raw_query_cudos(&self, request: CudosQuery) -> QuerierResult;
+
+    /// Returns a `CudosQuerier` sending its queries through this querier.
+    fn cudos(&self) -> CudosQuerier<'_>
+    where
+        Self: Sized,
+    {
+        CudosQuerier::new(self)
+    }
+}
+
+impl<'a, C: CustomQuery + From<CudosQuery>> CudosQueryExt for QuerierWrapper<'a, C> {
+    fn raw_query_cudos(&self, request: CudosQuery) -> QuerierResult {
+        let request: QueryRequest<C> = QueryRequest::Custom(C::from(request));
+        match to_vec(&request) {
+            Ok(raw) => self.raw_query(&raw),
+            Err(err) => SystemResult::Err(SystemError::InvalidRequest {
+                error: format!("Serializing QueryRequest: {}", err),
+                request: Binary::default(),
+            }),
+        }
+    }
+}
+
 pub struct CudosQuerier<'a> {
-    querier: &'a QuerierWrapper<'a, CudosQuery>,
+    pub(crate) querier: &'a dyn CudosQueryExt,
 }
 
 impl<'a> CudosQuerier<'a> {
-    pub fn new(querier: &'a QuerierWrapper<'a, CudosQuery>) -> Self {
+    /// Accepts `&deps.querier` for any custom query type implementing `From<CudosQuery>`.
+    pub fn new(querier: &'a dyn CudosQueryExt) -> Self {
         CudosQuerier { querier }
     }
 
+    // Parses the response and flattens the errors the same way as `QuerierWrapper::query`,
+    // which `CudosError` relies on to classify them.
+    fn query<T: DeserializeOwned>(&self, request: CudosQuery) -> StdResult<T> {
+        match self.querier.raw_query_cudos(request) {
+            SystemResult::Err(system_err) => Err(StdError::generic_err(format!(
+                "Querier system error: {}",
+                system_err
+            ))),
+            SystemResult::Ok(ContractResult::Err(contract_err)) => Err(StdError::generic_err(
+                format!("Querier contract error: {}", contract_err),
+            )),
+            SystemResult::Ok(ContractResult::Ok(value)) => from_binary(&value),
+        }
+    }
+
     pub fn query_denom_by_id<T: Into<String>>(&self, denom_id: T) -> StdResult<DenomResponse> {
         let request = CudosQuery::QueryDenomById {
             denom_id: denom_id.into(),
-        }
-        .into();
+        };
 
-        self.querier.query(&request)
+        self.query(request)
     }
 
     pub fn query_denom_by_name<T: Into<String>>(&self, denom_name: T) -> StdResult<DenomResponse> {
         let request = CudosQuery::QueryDenomByName {
             denom_name: denom_name.into(),
-        }
-        .into();
+        };
 
-        self.querier.query(&request)
+        self.query(request)
     }
 
     pub fn query_denom_by_symbol<T: Into<String>>(&self, symbol: T) -> StdResult<DenomResponse> {
         let request = CudosQuery::QueryDenomBySymbol {
             denom_symbol: symbol.into(),
-        }
-        .into();
+        };
 
-        self.querier.query(&request)
+        self.query(request)
     }
 
-    pub fn query_denoms<>(&self, pagination: Option<PaginationRequest>) -> StdResult<DenomsResponse> {
-        let request = CudosQuery::QueryDenoms {
-            pagination: pagination,
-        }
-        .into();
+    pub fn query_denoms(&self, pagination: Option<PaginationRequest>) -> StdResult<DenomsResponse> {
+        let request = CudosQuery::QueryDenoms { pagination };
 
-        self.querier.query(&request)
+        self.query(request)
     }
 
-    pub fn query_collection<T: Into<String>>(&self, denom_id: T, pagination: Option<PaginationRequest>) -> StdResult<CollectionResponse> {
+    pub fn query_collection<T: Into<String>>(
+        &self,
+        denom_id: T,
+        pagination: Option<PaginationRequest>,
+    ) -> StdResult<CollectionResponse> {
         let request = CudosQuery::QueryCollection {
             denom_id: denom_id.into(),
-            pagination: pagination.into(),
-        }
-        .into();
+            pagination,
+        };
 
-        self.querier.query(&request)
+        self.query(request)
     }
 
     pub fn query_supply<T: Into<String>>(&self, denom_id: T) -> StdResult<SupplyResponse> {
         let request = CudosQuery::QuerySupply {
             denom_id: denom_id.into(),
-        }
-        .into();
+        };
 
-        self.querier.query(&request)
+        self.query(request)
     }
 
     pub fn query_owner<T: Into<Option<String>>, D: Into<String>>(
@@ -78,11 +131,10 @@ impl<'a> CudosQuerier<'a> {
         let request = CudosQuery::QueryOwner {
             denom_id: denom_id.into(),
             address: address.into(),
-            pagination: pagination.into(),
-        }
-        .into();
+            pagination,
+        };
 
-        self.querier.query(&request)
+        self.query(request)
     }
 
     pub fn query_token<T: Into<String>>(
@@ -93,10 +145,9 @@ impl<'a> CudosQuerier<'a> {
         let request = CudosQuery::QueryToken {
             denom_id: denom_id.into(),
             token_id: token_id.into(),
-        }
-        .into();
+        };
 
-        self.querier.query(&request)
+        self.query(request)
     }
 
     pub fn query_approvals<T: Into<String>>(
@@ -107,10 +158,9 @@ impl<'a> CudosQuerier<'a> {
         let request = CudosQuery::QueryApprovals {
             denom_id: denom_id.into(),
             token_id: token_id.into(),
-        }
-        .into();
+        };
 
-        self.querier.query(&request)
+        self.query(request)
     }
 
     pub fn query_approved_for_all<T: Into<String>>(
@@ -121,9 +171,495 @@ impl<'a> CudosQuerier<'a> {
         let request = CudosQuery::QueryApprovedForAll {
             owner_address: owner_address.into(),
             operator_address: operator_address.into(),
+        };
+
+        self.query(request)
+    }
+}
+
+// Queries of the marketplace module.
+impl<'a> CudosQuerier<'a> {
+    pub fn query_marketplace_collection(
+        &self,
+        id: u64,
+    ) -> StdResult<MarketplaceCollectionResponse> {
+        let request = CudosQuery::QueryMarketplaceCollection { id };
+
+        self.query(request)
+    }
+
+    /// Returns the marketplace collection of the denom, including its royalties.
+    pub fn query_marketplace_collection_by_denom_id<T: Into<String>>(
+        &self,
+        denom_id: T,
+    ) -> StdResult<MarketplaceCollectionResponse> {
+        let request = CudosQuery::QueryMarketplaceCollectionByDenomId {
+            denom_id: denom_id.into(),
+        };
+
+        self.query(request)
+    }
+
+    pub fn query_marketplace_collections(
+        &self,
+        pagination: Option<PaginationRequest>,
+    ) -> StdResult<MarketplaceCollectionsResponse> {
+        let request = CudosQuery::QueryMarketplaceCollections { pagination };
+
+        self.query(request)
+    }
+
+    pub fn query_listing(&self, id: u64) -> StdResult<ListingResponse> {
+        let request = CudosQuery::QueryListing { id };
+
+        self.query(request)
+    }
+
+    pub fn query_listings(
+        &self,
+        pagination: Option<PaginationRequest>,
+    ) -> StdResult<ListingsResponse> {
+        let request = CudosQuery::QueryListings { pagination };
+
+        self.query(request)
+    }
+}
+
+// Queries of the addressbook module.
+impl<'a> CudosQuerier<'a> {
+    /// Returns the address stored under `network` and `label` in the addressbook of `creator`.
+    pub fn query_address<T: Into<String>>(
+        &self,
+        creator: T,
+        network: T,
+        label: T,
+    ) -> StdResult<AddressResponse> {
+        let request = CudosQuery::QueryAddress {
+            creator: creator.into(),
+            network: network.into(),
+            label: label.into(),
+        };
+
+        self.query(request)
+    }
+}
+
+// Queries of the gravity bridge module.
+impl<'a> CudosQuerier<'a> {
+    /// Returns the batches created but not yet relayed to Ethereum.
+    pub fn query_pending_batches(&self) -> StdResult<PendingBatchesResponse> {
+        let request = CudosQuery::QueryPendingBatches {};
+
+        self.query(request)
+    }
+
+    /// Returns the fees of the transfers waiting to be batched, per token.
+    pub fn query_batch_fees(&self) -> StdResult<BatchFeesResponse> {
+        let request = CudosQuery::QueryBatchFees {};
+
+        self.query(request)
+    }
+
+    /// Returns the nonce of the last Ethereum event observed by the validators.
+    pub fn query_last_observed_eth_nonce(&self) -> StdResult<LastObservedEthNonceResponse> {
+        let request = CudosQuery::QueryLastObservedEthNonce {};
+
+        self.query(request)
+    }
+
+    pub fn query_erc20_to_denom<T: Into<String>>(
+        &self,
+        erc20: T,
+    ) -> StdResult<Erc20ToDenomResponse> {
+        let request = CudosQuery::QueryErc20ToDenom {
+            erc20: erc20.into(),
+        };
+
+        self.query(request)
+    }
+
+    pub fn query_denom_to_erc20<T: Into<String>>(
+        &self,
+        denom: T,
+    ) -> StdResult<DenomToErc20Response> {
+        let request = CudosQuery::QueryDenomToErc20 {
+            denom: denom.into(),
+        };
+
+        self.query(request)
+    }
+}
+
+// Queries of the cudoMint module.
+impl<'a> CudosQuerier<'a> {
+    pub fn query_mint_params(&self) -> StdResult<MintParamsResponse> {
+        let request = CudosQuery::MintParams {};
+
+        self.query(request)
+    }
+
+    pub fn query_annual_provisions(&self) -> StdResult<AnnualProvisionsResponse> {
+        let request = CudosQuery::AnnualProvisions {};
+
+        self.query(request)
+    }
+}
+
+// Same queries as above, with the errors of the NFT module classified into `CudosError`.
+impl<'a> CudosQuerier<'a> {
+    pub fn try_query_denom_by_id<T: Into<String>>(
+        &self,
+        denom_id: T,
+    ) -> Result<DenomResponse, CudosError> {
+        Ok(self.query_denom_by_id(denom_id)?)
+    }
+
+    pub fn try_query_denom_by_name<T: Into<String>>(
+        &self,
+        denom_name: T,
+    ) -> Result<DenomResponse, CudosError> {
+        Ok(self.query_denom_by_name(denom_name)?)
+    }
+
+    pub fn try_query_denom_by_symbol<T: Into<String>>(
+        &self,
+        symbol: T,
+    ) -> Result<DenomResponse, CudosError> {
+        Ok(self.query_denom_by_symbol(symbol)?)
+    }
+
+    pub fn try_query_denoms(
+        &self,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<DenomsResponse, CudosError> {
+        Ok(self.query_denoms(pagination)?)
+    }
+
+    pub fn try_query_collection<T: Into<String>>(
+        &self,
+        denom_id: T,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<CollectionResponse, CudosError> {
+        Ok(self.query_collection(denom_id, pagination)?)
+    }
+
+    pub fn try_query_supply<T: Into<String>>(
+        &self,
+        denom_id: T,
+    ) -> Result<SupplyResponse, CudosError> {
+        Ok(self.query_supply(denom_id)?)
+    }
+
+    pub fn try_query_owner<T: Into<Option<String>>, D: Into<String>>(
+        &self,
+        denom_id: T,
+        address: D,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<OwnerCollectionResponse, CudosError> {
+        Ok(self.query_owner(denom_id, address, pagination)?)
+    }
+
+    pub fn try_query_token<T: Into<String>>(
+        &self,
+        denom_id: T,
+        token_id: T,
+    ) -> Result<QueryNFTResponse, CudosError> {
+        Ok(self.query_token(denom_id, token_id)?)
+    }
+
+    pub fn try_query_approvals<T: Into<String>>(
+        &self,
+        denom_id: T,
+        token_id: T,
+    ) -> Result<QueryApprovalsResponse, CudosError> {
+        Ok(self.query_approvals(denom_id, token_id)?)
+    }
+
+    pub fn try_query_approved_for_all<T: Into<String>>(
+        &self,
+        owner_address: T,
+        operator_address: T,
+    ) -> Result<QueryApprovedForAllResponse, CudosError> {
+        Ok(self.query_approved_for_all(owner_address, operator_address)?)
+    }
+}
+
+// Existence checks: a denom or token that is not found yields `Ok(None)`.
+//
+// The module reports a missing denom with the code of `invalid denom`, which it also uses for
+// malformed and duplicated denoms. A query can't duplicate a denom, so once its input is
+// validated here that code means the denom is missing.
+impl<'a> CudosQuerier<'a> {
+    pub fn query_denom_by_id_opt<T: Into<String>>(&self, denom_id: T) -> StdResult<Option<Denom>> {
+        let denom_id = denom_id.into();
+        validate_denom_id(&denom_id)?;
+        missing_to_none(self.try_query_denom_by_id(denom_id).map(|res| res.denom))
+    }
+
+    pub fn query_denom_by_name_opt<T: Into<String>>(
+        &self,
+        denom_name: T,
+    ) -> StdResult<Option<Denom>> {
+        let denom_name = denom_name.into();
+        validate_denom_name(&denom_name)?;
+        missing_to_none(
+            self.try_query_denom_by_name(denom_name)
+                .map(|res| res.denom),
+        )
+    }
+
+    pub fn query_denom_by_symbol_opt<T: Into<String>>(
+        &self,
+        symbol: T,
+    ) -> StdResult<Option<Denom>> {
+        let symbol = symbol.into();
+        validate_symbol(&symbol)?;
+        missing_to_none(self.try_query_denom_by_symbol(symbol).map(|res| res.denom))
+    }
+
+    /// Also returns `Ok(None)` when the denom itself does not exist.
+    pub fn query_token_opt<T: Into<String>>(
+        &self,
+        denom_id: T,
+        token_id: T,
+    ) -> StdResult<Option<NFT>> {
+        let (denom_id, token_id) = (denom_id.into(), token_id.into());
+        validate_denom_id(&denom_id)?;
+        validate_token_id(&token_id)?;
+        missing_to_none(self.try_query_token(denom_id, token_id).map(|res| res.nft))
+    }
+}
+
+// Iterators that follow `next_key` across pages, yielding one item at a time.
+impl<'a> CudosQuerier<'a> {
+    pub fn denoms_iter(&self, page_size: u64) -> PageIter<'_, Denom> {
+        PageIter::new(page_size, move |pagination| {
+            let res = self.query_denoms(Some(pagination))?;
+            Ok((
+                res.denoms.unwrap_or_default(),
+                res.pagination.and_then(|p| p.next_key),
+            ))
+        })
+    }
+
+    pub fn collection_nfts_iter<T: Into<String>>(
+        &self,
+        denom_id: T,
+        page_size: u64,
+    ) -> PageIter<'_, NFT> {
+        let denom_id = denom_id.into();
+        PageIter::new(page_size, move |pagination| {
+            let res = self.query_collection(denom_id.clone(), Some(pagination))?;
+            Ok((
+                res.collection.and_then(|c| c.nfts).unwrap_or_default(),
+                res.pagination.and_then(|p| p.next_key),
+            ))
+        })
+    }
+
+    /// Yields `(denom_id, token_id)` pairs of the NFTs owned by `address`, optionally
+    /// restricted to a single denom.
+    pub fn owner_tokens_iter<T: Into<Option<String>>, D: Into<String>>(
+        &self,
+        address: D,
+        denom_id: T,
+        page_size: u64,
+    ) -> PageIter<'_, (String, String)> {
+        let address = address.into();
+        let denom_id = denom_id.into();
+        PageIter::new(page_size, move |pagination| {
+            let res = self.query_owner(denom_id.clone(), address.clone(), Some(pagination))?;
+            let tokens = res
+                .iter_tokens()
+                .map(|(denom_id, token_id)| (denom_id.to_string(), token_id.to_string()))
+                .collect();
+            Ok((tokens, res.pagination.and_then(|p| p.next_key)))
+        })
+    }
+
+    pub fn marketplace_collections_iter(
+        &self,
+        page_size: u64,
+    ) -> PageIter<'_, MarketplaceCollection> {
+        PageIter::new(page_size, move |pagination| {
+            let res = self.query_marketplace_collections(Some(pagination))?;
+            Ok((res.collections, res.pagination.and_then(|p| p.next_key)))
+        })
+    }
+
+    pub fn listings_iter(&self, page_size: u64) -> PageIter<'_, Listing> {
+        PageIter::new(page_size, move |pagination| {
+            let res = self.query_listings(Some(pagination))?;
+            Ok((res.listings, res.pagination.and_then(|p| p.next_key)))
+        })
+    }
+}
+
+fn missing_to_none<T>(result: Result<T, CudosError>) -> StdResult<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(err) if err.is_not_found() || matches!(err, CudosError::InvalidDenom(_)) => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cosmwasm_std::testing::MockQuerier;
+    use cosmwasm_std::{from_slice, Querier};
+    use schemars::JsonSchema;
+    use serde::{Deserialize, Serialize};
+
+    use crate::testing::{mock_cudos_dependencies, CudosMockQuerier};
+
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    enum MyQuery {
+        Cudos(CudosQuery),
+        Other {},
+    }
+
+    impl CustomQuery for MyQuery {}
+
+    impl From<CudosQuery> for MyQuery {
+        fn from(query: CudosQuery) -> Self {
+            MyQuery::Cudos(query)
+        }
+    }
+
+    // Answers the wrapped Cudos queries from the NFT module of `CudosMockQuerier`.
+    struct MyQuerier {
+        cudos: CudosMockQuerier,
+    }
+
+    impl Querier for MyQuerier {
+        fn raw_query(&self, bin_request: &[u8]) -> QuerierResult {
+            match from_slice(bin_request) {
+                Ok(QueryRequest::Custom(MyQuery::Cudos(query))) => {
+                    self.cudos.handle_query(&QueryRequest::Custom(query))
+                }
+                _ => SystemResult::Err(SystemError::UnsupportedRequest {
+                    kind: "custom".to_string(),
+                }),
+            }
         }
-        .into();
+    }
+
+    #[test]
+    fn queries_through_a_wrapping_query_type() {
+        let mut cudos = CudosMockQuerier::default();
+        cudos
+            .nft
+            .issue_denom("kittens", "Kittens", "KIT", None, "creator")
+            .unwrap();
+        let token_id = cudos
+            .nft
+            .mint_nft("kittens", "first", None, None, "owner")
+            .unwrap();
+        let querier = MyQuerier { cudos };
+        let wrapper = QuerierWrapper::<MyQuery>::new(&querier);
+
+        let nft = wrapper
+            .cudos()
+            .query_token("kittens", token_id.as_str())
+            .unwrap()
+            .nft;
+        assert_eq!(nft.id, token_id);
+        assert_eq!(nft.owner, "owner");
+
+        let denom = wrapper.cudos().query_denom_by_id("kittens").unwrap().denom;
+        assert_eq!(denom.name, "Kittens");
+    }
+
+    fn assert_other_error<T: std::fmt::Debug>(result: StdResult<Option<T>>, expected: &str) {
+        let err = result.unwrap_err();
+        assert!(err.to_string().contains(expected), "{}", err);
+    }
+
+    #[test]
+    fn opt_queries_of_denoms() {
+        let mut deps = mock_cudos_dependencies();
+        deps.querier
+            .nft
+            .issue_denom("kittens", "Kittens", "KIT", None, "creator")
+            .unwrap();
+        let deps = deps.as_ref();
+        let querier = CudosQuerier::new(&deps.querier);
+
+        let by_id = querier.query_denom_by_id_opt("kittens").unwrap().unwrap();
+        assert_eq!(by_id.name, "Kittens");
+        let by_name = querier.query_denom_by_name_opt("Kittens").unwrap().unwrap();
+        assert_eq!(by_name, by_id);
+        let by_symbol = querier.query_denom_by_symbol_opt("KIT").unwrap().unwrap();
+        assert_eq!(by_symbol, by_id);
+
+        assert_eq!(querier.query_denom_by_id_opt("puppies").unwrap(), None);
+        assert_eq!(querier.query_denom_by_name_opt("Puppies").unwrap(), None);
+        assert_eq!(querier.query_denom_by_symbol_opt("PUP").unwrap(), None);
+
+        // Malformed input is rejected instead of being reported as missing.
+        assert_other_error(querier.query_denom_by_id_opt("Kittens"), "invalid denom id");
+        assert_other_error(querier.query_denom_by_name_opt(" "), "invalid denom name");
+        assert_other_error(
+            querier.query_denom_by_symbol_opt(""),
+            "invalid denom symbol",
+        );
+    }
+
+    #[test]
+    fn opt_queries_of_tokens() {
+        let mut deps = mock_cudos_dependencies();
+        deps.querier
+            .nft
+            .issue_denom("kittens", "Kittens", "KIT", None, "creator")
+            .unwrap();
+        let token_id = deps
+            .querier
+            .nft
+            .mint_nft("kittens", "first", None, None, "owner")
+            .unwrap();
+        let deps = deps.as_ref();
+        let querier = CudosQuerier::new(&deps.querier);
+
+        let nft = querier
+            .query_token_opt("kittens", token_id.as_str())
+            .unwrap()
+            .unwrap();
+        assert_eq!(nft.name.as_deref(), Some("first"));
+
+        assert_eq!(querier.query_token_opt("kittens", "2").unwrap(), None);
+        assert_eq!(querier.query_token_opt("puppies", "1").unwrap(), None);
+
+        assert_other_error(
+            querier.query_token_opt("kittens", "first"),
+            "invalid token id",
+        );
+        assert_other_error(querier.query_token_opt("Kittens", "1"), "invalid denom id");
+    }
+
+    #[test]
+    fn opt_queries_propagate_other_errors() {
+        let mock = MockQuerier::<CudosQuery>::new(&[]);
+        let wrapper = QuerierWrapper::<CudosQuery>::new(&mock);
+        let querier = CudosQuerier::new(&wrapper);
 
-        self.querier.query(&request)
+        assert_other_error(
+            querier.query_denom_by_id_opt("kittens"),
+            "Querier system error",
+        );
+        assert_other_error(
+            querier.query_denom_by_name_opt("Kittens"),
+            "Querier system error",
+        );
+        assert_other_error(
+            querier.query_denom_by_symbol_opt("KIT"),
+            "Querier system error",
+        );
+        assert_other_error(
+            querier.query_token_opt("kittens", "1"),
+            "Querier system error",
+        );
     }
 }
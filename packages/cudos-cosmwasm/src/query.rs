@@ -1,7 +1,7 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use cosmwasm_std::CustomQuery;
+use cosmwasm_std::{Coin, CustomQuery, Decimal, Decimal256, Uint128, Uint256};
 
 // implement custom query
 impl CustomQuery for CudosQuery {}
@@ -46,6 +46,41 @@ pub enum CudosQuery {
         owner_address: String,
         operator_address: String,
     },
+    // Queries of the marketplace module.
+    QueryMarketplaceCollection {
+        id: u64,
+    },
+    QueryMarketplaceCollectionByDenomId {
+        denom_id: String,
+    },
+    QueryMarketplaceCollections {
+        pagination: Option<PaginationRequest>,
+    },
+    QueryListing {
+        id: u64,
+    },
+    QueryListings {
+        pagination: Option<PaginationRequest>,
+    },
+    // Queries of the addressbook module.
+    QueryAddress {
+        creator: String,
+        network: String,
+        label: String,
+    },
+    // Queries of the gravity bridge module.
+    QueryPendingBatches {},
+    QueryBatchFees {},
+    QueryLastObservedEthNonce {},
+    QueryErc20ToDenom {
+        erc20: String,
+    },
+    QueryDenomToErc20 {
+        denom: String,
+    },
+    // Queries of the cudoMint module.
+    MintParams {},
+    AnnualProvisions {},
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -60,25 +95,46 @@ pub struct QueryApprovedForAllResponse {
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct OwnerCollectionResponse {
-    owner: Owner,
+    pub owner: Owner,
     pub pagination: Option<PageResponse>,
 }
 
+impl OwnerCollectionResponse {
+    /// Token ids owned in the given denom, empty if the owner has none.
+    pub fn token_ids_for(&self, denom_id: &str) -> &[String] {
+        self.owner
+            .id_collections
+            .iter()
+            .find(|collection| collection.denom_id == denom_id)
+            .map_or(&[], |collection| collection.token_ids.as_slice())
+    }
+
+    /// Iterates over all owned tokens as `(denom_id, token_id)` pairs.
+    pub fn iter_tokens(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.owner.id_collections.iter().flat_map(|collection| {
+            collection
+                .token_ids
+                .iter()
+                .map(move |token_id| (collection.denom_id.as_str(), token_id.as_str()))
+        })
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct Owner {
-    address: String,
-    id_collections: Vec<IDCollection>,
+    pub address: String,
+    pub id_collections: Vec<IDCollection>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct IDCollection {
-    denom_id: String,
-    token_ids: Vec<String>,
+    pub denom_id: String,
+    pub token_ids: Vec<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct SupplyResponse {
-    amount: u64,
+    pub amount: u64,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -98,6 +154,21 @@ pub struct Denom {
     pub name: String,
     pub schema: Option<String>,
     pub creator: String,
+    // Fields below are missing from the responses of older nodes.
+    #[serde(default)]
+    pub symbol: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub uri: Option<String>,
+    #[serde(default)]
+    pub uri_hash: Option<String>,
+    #[serde(default)]
+    pub data: Option<String>,
+    #[serde(default)]
+    pub traits: Option<String>,
+    #[serde(default)]
+    pub minter: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, JsonSchema)]
@@ -109,25 +180,25 @@ pub struct CollectionResponse {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct PaginationRequest {
     // key is a value returned in PageResponse.next_key to begin
-	// querying the next page most efficiently. Only one of offset or key
-	// should be set.
-	pub key: Option<String>,
-	// offset is a numeric offset that can be used when key is unavailable.
-	// It is less efficient than using key. Only one of offset or key should
-	// be set.
-	pub offset: Option<u64>,
-	// limit is the total number of results to be returned in the result page.
-	// If left empty it will default to a value to be set by each app.
-	pub limit: Option<u64>,
-	// count_total is set to true  to indicate that the result set should include
-	// a count of the total number of items available for pagination in UIs.
-	// count_total is only respected when offset is used. It is ignored when key
-	// is set.
-	pub count_total: Option<bool>,
-	// reverse is set to true if results are to be returned in the descending order.
-	//
-	// Since: cosmos-sdk 0.43
-	pub reverse: Option<bool>,
+    // querying the next page most efficiently. Only one of offset or key
+    // should be set.
+    pub key: Option<String>,
+    // offset is a numeric offset that can be used when key is unavailable.
+    // It is less efficient than using key. Only one of offset or key should
+    // be set.
+    pub offset: Option<u64>,
+    // limit is the total number of results to be returned in the result page.
+    // If left empty it will default to a value to be set by each app.
+    pub limit: Option<u64>,
+    // count_total is set to true  to indicate that the result set should include
+    // a count of the total number of items available for pagination in UIs.
+    // count_total is only respected when offset is used. It is ignored when key
+    // is set.
+    pub count_total: Option<bool>,
+    // reverse is set to true if results are to be returned in the descending order.
+    //
+    // Since: cosmos-sdk 0.43
+    pub reverse: Option<bool>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -156,3 +227,390 @@ pub struct NFT {
 pub struct QueryNFTResponse {
     pub nft: NFT,
 }
+
+/// A share of the price of an NFT paid to `address`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Royalty {
+    pub address: String,
+    /// Percent of the price, e.g. `2.5` for 2.5%.
+    pub percent: Decimal,
+}
+
+/// A denom published on the marketplace.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MarketplaceCollection {
+    pub id: u64,
+    pub denom_id: String,
+    pub mint_royalties: Vec<Royalty>,
+    pub resale_royalties: Vec<Royalty>,
+    pub verified: bool,
+    pub owner: String,
+}
+
+/// An NFT listed for sale on the marketplace.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Listing {
+    pub id: u64,
+    pub denom_id: String,
+    pub token_id: String,
+    pub price: Coin,
+    pub owner: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MarketplaceCollectionResponse {
+    pub collection: MarketplaceCollection,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MarketplaceCollectionsResponse {
+    pub collections: Vec<MarketplaceCollection>,
+    pub pagination: Option<PageResponse>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ListingResponse {
+    pub listing: Listing,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ListingsResponse {
+    pub listings: Vec<Listing>,
+    pub pagination: Option<PageResponse>,
+}
+
+/// An address on another network, stored in the addressbook of `creator`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AddressbookEntry {
+    pub creator: String,
+    pub network: String,
+    pub label: String,
+    pub value: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AddressResponse {
+    pub address: AddressbookEntry,
+}
+
+/// An amount of an ERC20 token, identified by the address of its contract.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Erc20Token {
+    pub contract: String,
+    pub amount: Uint128,
+}
+
+/// A transfer to Ethereum included in a batch.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OutgoingTransferTx {
+    pub id: u64,
+    pub sender: String,
+    pub dest_address: String,
+    pub erc20_token: Erc20Token,
+    pub erc20_fee: Erc20Token,
+}
+
+/// A batch of transfers of one token waiting to be relayed to Ethereum.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OutgoingTxBatch {
+    pub batch_nonce: u64,
+    pub batch_timeout: u64,
+    pub transactions: Vec<OutgoingTransferTx>,
+    pub token_contract: String,
+    /// Cosmos block height at which the batch was created.
+    pub block: u64,
+}
+
+/// The fees of the unbatched transfers of one token.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BatchFees {
+    pub token: String,
+    pub total_fees: Uint128,
+    pub tx_count: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PendingBatchesResponse {
+    pub batches: Vec<OutgoingTxBatch>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BatchFeesResponse {
+    pub batch_fees: Vec<BatchFees>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LastObservedEthNonceResponse {
+    pub nonce: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Erc20ToDenomResponse {
+    pub denom: String,
+    /// Whether the token was created on Cudos and bridged to Ethereum.
+    pub cosmos_originated: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DenomToErc20Response {
+    pub erc20: String,
+    pub cosmos_originated: bool,
+}
+
+/// Parameters of the cudoMint module, which mints the staking rewards.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MintParams {
+    pub mint_denom: String,
+    /// Amount by which the minted rewards are incremented.
+    pub incrementation_amount: Uint256,
+    pub blocks_per_day: Uint256,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MintParamsResponse {
+    pub params: MintParams,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AnnualProvisionsResponse {
+    /// Amount of `mint_denom` expected to be minted over the next year.
+    pub annual_provisions: Decimal256,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fmt::Debug;
+    use std::str::FromStr;
+
+    use cosmwasm_std::{from_slice, to_vec};
+    use serde::de::DeserializeOwned;
+
+    use crate::querier::CudosQuerier;
+    use crate::testing::mock_cudos_dependencies;
+
+    // Parses a response the way the node encodes it and checks that it serializes back to the
+    // same value.
+    fn round_trip<T: Serialize + DeserializeOwned + PartialEq + Debug>(json: &str) -> T {
+        let value: T = from_slice(json.as_bytes()).unwrap();
+        assert_eq!(from_slice::<T>(&to_vec(&value).unwrap()).unwrap(), value);
+        value
+    }
+
+    #[test]
+    fn owner_tokens_by_denom() {
+        let res: OwnerCollectionResponse = from_slice(
+            br#"{
+                "owner": {
+                    "address": "cudos1owner",
+                    "id_collections": [
+                        {"denom_id": "kittens", "token_ids": ["1", "3"]},
+                        {"denom_id": "puppies", "token_ids": ["2"]}
+                    ]
+                },
+                "pagination": {"next_key": null, "total": 0}
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(res.token_ids_for("kittens"), ["1", "3"]);
+        assert_eq!(res.token_ids_for("puppies"), ["2"]);
+        assert!(res.token_ids_for("ducks").is_empty());
+        assert_eq!(
+            res.iter_tokens().collect::<Vec<_>>(),
+            [("kittens", "1"), ("kittens", "3"), ("puppies", "2")]
+        );
+    }
+
+    #[test]
+    fn owner_tokens_from_the_mock() {
+        let mut deps = mock_cudos_dependencies();
+        let module = &mut deps.querier.nft;
+        for (id, name) in [
+            ("kittens", "Kittens"),
+            ("puppies", "Puppies"),
+            ("ducks", "Ducks"),
+        ] {
+            module.issue_denom(id, name, name, None, "creator").unwrap();
+        }
+        module
+            .mint_nft("kittens", "a", None, None, "owner")
+            .unwrap();
+        module
+            .mint_nft("kittens", "b", None, None, "owner")
+            .unwrap();
+        module
+            .mint_nft("puppies", "c", None, None, "owner")
+            .unwrap();
+        module.mint_nft("ducks", "d", None, None, "other").unwrap();
+        let deps = deps.as_ref();
+        let querier = CudosQuerier::new(&deps.querier);
+
+        let res = querier.query_owner(None, "owner", None).unwrap();
+        assert_eq!(res.token_ids_for("kittens"), ["1", "2"]);
+        assert_eq!(res.token_ids_for("puppies"), ["1"]);
+        assert!(res.token_ids_for("ducks").is_empty());
+        assert_eq!(
+            res.iter_tokens().collect::<Vec<_>>(),
+            [("kittens", "1"), ("kittens", "2"), ("puppies", "1")]
+        );
+
+        let res = querier
+            .query_owner(Some("puppies".to_string()), "owner", None)
+            .unwrap();
+        assert!(res.token_ids_for("kittens").is_empty());
+        assert_eq!(res.iter_tokens().collect::<Vec<_>>(), [("puppies", "1")]);
+    }
+
+    #[test]
+    fn marketplace_responses() {
+        let res: MarketplaceCollectionsResponse = round_trip(
+            r#"{
+                "collections": [{
+                    "id": 1,
+                    "denom_id": "kittens",
+                    "mint_royalties": [
+                        {"address": "cudos1artist", "percent": "2.500000000000000000"}
+                    ],
+                    "resale_royalties": [
+                        {"address": "cudos1artist", "percent": "0.010000000000000000"},
+                        {"address": "cudos1gallery", "percent": "10"}
+                    ],
+                    "verified": true,
+                    "owner": "cudos1owner"
+                }],
+                "pagination": {"next_key": "AQ==", "total": 2}
+            }"#,
+        );
+        let collection = &res.collections[0];
+        assert_eq!(collection.mint_royalties[0].percent, Decimal::percent(250));
+        assert_eq!(
+            collection.resale_royalties[0].percent,
+            Decimal::from_str("0.01").unwrap()
+        );
+        assert_eq!(
+            collection.resale_royalties[1].percent,
+            Decimal::percent(1000)
+        );
+        assert_eq!(res.pagination.unwrap().next_key.as_deref(), Some("AQ=="));
+
+        let res: ListingResponse = round_trip(
+            r#"{
+                "listing": {
+                    "id": 7,
+                    "denom_id": "kittens",
+                    "token_id": "1",
+                    "price": {"denom": "acudos", "amount": "1000000000000000000000"},
+                    "owner": "cudos1owner"
+                }
+            }"#,
+        );
+        assert_eq!(
+            res.listing.price.amount,
+            Uint128::new(1_000_000_000_000_000_000_000)
+        );
+
+        let res: ListingsResponse =
+            round_trip(r#"{"listings": [], "pagination": {"next_key": null, "total": 0}}"#);
+        assert!(res.listings.is_empty());
+    }
+
+    #[test]
+    fn addressbook_responses() {
+        let res: AddressResponse = round_trip(
+            r#"{
+                "address": {
+                    "creator": "cudos1creator",
+                    "network": "ETH",
+                    "label": "1@payouts",
+                    "value": "0x52908400098527886E0F7030069857D2E4169EE7"
+                }
+            }"#,
+        );
+        assert_eq!(res.address.network, "ETH");
+        assert_eq!(
+            res.address.value,
+            "0x52908400098527886E0F7030069857D2E4169EE7"
+        );
+    }
+
+    #[test]
+    fn gravity_responses() {
+        let res: PendingBatchesResponse = round_trip(
+            r#"{
+                "batches": [{
+                    "batch_nonce": 3,
+                    "batch_timeout": 15000,
+                    "transactions": [{
+                        "id": 12,
+                        "sender": "cudos1sender",
+                        "dest_address": "0x52908400098527886E0F7030069857D2E4169EE7",
+                        "erc20_token": {
+                            "contract": "0x817bbDbC3e8A1204f3691d14bB44992841e3dB35",
+                            "amount": "340282366920938463463374607431768211455"
+                        },
+                        "erc20_fee": {
+                            "contract": "0x817bbDbC3e8A1204f3691d14bB44992841e3dB35",
+                            "amount": "1000000000000000000"
+                        }
+                    }],
+                    "token_contract": "0x817bbDbC3e8A1204f3691d14bB44992841e3dB35",
+                    "block": 1200
+                }]
+            }"#,
+        );
+        let tx = &res.batches[0].transactions[0];
+        assert_eq!(tx.erc20_token.amount, Uint128::MAX);
+        assert_eq!(tx.erc20_fee.amount, Uint128::new(1_000_000_000_000_000_000));
+
+        let res: BatchFeesResponse = round_trip(
+            r#"{
+                "batch_fees": [{
+                    "token": "0x817bbDbC3e8A1204f3691d14bB44992841e3dB35",
+                    "total_fees": "25000000000000000000",
+                    "tx_count": 4
+                }]
+            }"#,
+        );
+        assert_eq!(
+            res.batch_fees[0].total_fees,
+            Uint128::new(25_000_000_000_000_000_000)
+        );
+
+        let res: LastObservedEthNonceResponse = round_trip(r#"{"nonce": 42}"#);
+        assert_eq!(res.nonce, 42);
+        let res: Erc20ToDenomResponse =
+            round_trip(r#"{"denom": "acudos", "cosmos_originated": true}"#);
+        assert!(res.cosmos_originated);
+        let res: DenomToErc20Response = round_trip(
+            r#"{"erc20": "0x817bbDbC3e8A1204f3691d14bB44992841e3dB35", "cosmos_originated": false}"#,
+        );
+        assert!(!res.cosmos_originated);
+    }
+
+    #[test]
+    fn mint_responses() {
+        let res: MintParamsResponse = round_trip(
+            r#"{
+                "params": {
+                    "mint_denom": "acudos",
+                    "incrementation_amount": "340282366920938463463374607431768211456",
+                    "blocks_per_day": "17280"
+                }
+            }"#,
+        );
+        assert_eq!(
+            res.params.incrementation_amount,
+            Uint256::from(u128::MAX) + Uint256::from(1u8)
+        );
+        assert_eq!(res.params.blocks_per_day, Uint256::from(17280u32));
+
+        let res: AnnualProvisionsResponse =
+            round_trip(r#"{"annual_provisions": "1234567890123456789012345.678900000000000000"}"#);
+        assert_eq!(
+            res.annual_provisions,
+            Decimal256::from_str("1234567890123456789012345.6789").unwrap()
+        );
+    }
+}
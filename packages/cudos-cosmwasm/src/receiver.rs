@@ -0,0 +1,187 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use cosmwasm_std::{to_binary, Addr, Binary, CosmosMsg, MessageInfo, StdResult, WasmMsg};
+
+use crate::error::CudosError;
+use crate::msg::{create_transfer_nft_msg, CudosMsg};
+use crate::querier::CudosQuerier;
+use crate::query::NFT;
+
+/// Sent to the receiving contract after an NFT is transferred to it with
+/// `create_send_nft_msgs`. The JSON is a superset of `Cw721ReceiveMsg`, so cw721 receivers
+/// accept it too.
+///
+/// # Security
+///
+/// Anyone can execute `ReceiveNft` with any content, so none of its fields can be trusted on
+/// their own. A forged message can name any `sender`, and after a plain `TransferNftMsg` to
+/// the receiver, which does not notify it, anyone can execute `ReceiveNft` for that token
+/// before the real sender does and be credited with it. Only `ReceiveNft` executed by a
+/// contract that transfers the NFT and notifies the receiver in the same transaction, like
+/// the cw721 adapter, is safe. Receivers must check the caller and the ownership of the NFT
+/// with `verify_received` before crediting `sender`, and must not credit the same token twice.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct NftReceiveMsg {
+    /// The address that sent the NFT, as claimed by the caller of `ReceiveNft`. Only trust it
+    /// once `verify_received` has passed.
+    pub sender: String,
+    pub token_id: String,
+    pub denom_id: String,
+    pub msg: Binary,
+}
+
+/// Receivers add the `ReceiveNft` variant to their own `ExecuteMsg`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReceiverExecuteMsg {
+    ReceiveNft(NftReceiveMsg),
+}
+
+impl NftReceiveMsg {
+    pub fn into_binary(self) -> StdResult<Binary> {
+        to_binary(&ReceiverExecuteMsg::ReceiveNft(self))
+    }
+
+    pub fn into_cosmos_msg<C>(self, contract_addr: impl Into<String>) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr: contract_addr.into(),
+            msg: self.into_binary()?,
+            funds: vec![],
+        }
+        .into())
+    }
+
+    /// Checks that `ReceiveNft` was executed by one of `trusted_callers`, the contracts that
+    /// send NFTs with `create_send_nft_msgs`, and that the NFT is owned by `receiver`, normally
+    /// `env.contract.address`. `info` is the `MessageInfo` of the `ReceiveNft` execution.
+    ///
+    /// Receivers must call this before crediting `sender`, see the security notes of
+    /// `NftReceiveMsg`.
+    pub fn verify_received(
+        &self,
+        querier: &CudosQuerier,
+        info: &MessageInfo,
+        receiver: impl AsRef<str>,
+        trusted_callers: &[Addr],
+    ) -> Result<NFT, CudosError> {
+        if !trusted_callers.contains(&info.sender) {
+            return Err(CudosError::UntrustedCaller {
+                caller: info.sender.to_string(),
+            });
+        }
+
+        let nft = querier
+            .try_query_token(self.denom_id.as_str(), self.token_id.as_str())?
+            .nft;
+
+        if nft.owner != receiver.as_ref() {
+            return Err(CudosError::NotReceived {
+                denom_id: self.denom_id.clone(),
+                token_id: self.token_id.clone(),
+                owner: nft.owner,
+            });
+        }
+        Ok(nft)
+    }
+}
+
+/// Transfers the NFT to `contract` and then notifies it with `ReceiveNft`. Both messages are
+/// executed in the same transaction, so the receiver can reject the deposit by failing. The
+/// notification is executed by the contract returning the messages, which the receiver must
+/// trust in `verify_received`.
+pub fn create_send_nft_msgs<M: From<CudosMsg>>(
+    denom_id: String,
+    token_id: String,
+    from: String,
+    contract: String,
+    msg: Binary,
+    sender: String,
+    contract_address_signer: String,
+) -> StdResult<Vec<CosmosMsg<M>>> {
+    let receive = NftReceiveMsg {
+        sender: sender.clone(),
+        token_id: token_id.clone(),
+        denom_id: denom_id.clone(),
+        msg,
+    };
+    let notify = receive.into_cosmos_msg(contract.clone())?;
+    let transfer = create_transfer_nft_msg(
+        denom_id,
+        token_id,
+        from,
+        contract,
+        sender,
+        contract_address_signer,
+    );
+
+    Ok(vec![transfer, notify])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::mock_cudos_dependencies;
+    use cosmwasm_std::testing::mock_info;
+
+    const ESCROW: &str = "escrow";
+    const ADAPTER: &str = "adapter";
+
+    fn receive(token_id: &str) -> NftReceiveMsg {
+        NftReceiveMsg {
+            sender: "owner".to_string(),
+            token_id: token_id.to_string(),
+            denom_id: "kittens".to_string(),
+            msg: Binary::default(),
+        }
+    }
+
+    #[test]
+    fn verify_received() {
+        let mut deps = mock_cudos_dependencies();
+        let nft = &mut deps.querier.nft;
+        nft.issue_denom("kittens", "Kittens", "KIT", None, "creator")
+            .unwrap();
+        nft.mint_nft("kittens", "deposited", None, None, ESCROW)
+            .unwrap();
+        nft.mint_nft("kittens", "kept", None, None, "owner")
+            .unwrap();
+        let deps = deps.as_ref();
+        let querier = CudosQuerier::new(&deps.querier);
+        let trusted = [Addr::unchecked(ADAPTER)];
+
+        let nft = receive("1")
+            .verify_received(&querier, &mock_info(ADAPTER, &[]), ESCROW, &trusted)
+            .unwrap();
+        assert_eq!(nft.owner, ESCROW);
+
+        // The claimed sender executing `ReceiveNft` itself could be front running a plain
+        // transfer of someone else.
+        let err = receive("1")
+            .verify_received(&querier, &mock_info("owner", &[]), ESCROW, &trusted)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            CudosError::UntrustedCaller {
+                caller: "owner".to_string()
+            }
+        );
+
+        let err = receive("2")
+            .verify_received(&querier, &mock_info(ADAPTER, &[]), ESCROW, &trusted)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            CudosError::NotReceived {
+                denom_id: "kittens".to_string(),
+                token_id: "2".to_string(),
+                owner: "owner".to_string(),
+            }
+        );
+
+        let err = receive("3")
+            .verify_received(&querier, &mock_info(ADAPTER, &[]), ESCROW, &trusted)
+            .unwrap_err();
+        assert!(err.is_not_found());
+    }
+}
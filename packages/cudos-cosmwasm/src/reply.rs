@@ -0,0 +1,343 @@
+use cosmwasm_std::{Reply, SubMsgResult};
+
+use crate::error::CudosError;
+use crate::events::{
+    ApprovedAll, ApprovedNft, BurnedNft, CudosEvent, EditedNft, IssuedDenom, MintedNft, RevokedNft,
+    TransferredDenom, TransferredNft,
+};
+
+// Parsers for the replies of `CudosMsg` submessages. They read the events emitted by the NFT
+// module, which is the only way to learn e.g. the id assigned to a minted token. A failed
+// submessage is returned as its module error, classified by the code wasmd redacts it to.
+
+/// Parses the first event of type `T::EVENT_TYPE` in the reply.
+pub fn parse_reply<T: CudosEvent>(reply: &Reply) -> Result<T, CudosError> {
+    let response = match &reply.result {
+        SubMsgResult::Ok(response) => response,
+        SubMsgResult::Err(err) => return Err(CudosError::from_module_error(err.as_str())),
+    };
+
+    let event = response
+        .events
+        .iter()
+        .find(|event| event.ty == T::EVENT_TYPE)
+        .ok_or_else(|| {
+            CudosError::InvalidReply(format!(
+                "reply {} has no `{}` event",
+                reply.id,
+                T::EVENT_TYPE
+            ))
+        })?;
+    T::try_from(event)
+}
+
+pub fn parse_issue_denom_reply(reply: &Reply) -> Result<IssuedDenom, CudosError> {
+    parse_reply(reply)
+}
+
+pub fn parse_mint_nft_reply(reply: &Reply) -> Result<MintedNft, CudosError> {
+    parse_reply(reply)
+}
+
+pub fn parse_edit_nft_reply(reply: &Reply) -> Result<EditedNft, CudosError> {
+    parse_reply(reply)
+}
+
+pub fn parse_transfer_nft_reply(reply: &Reply) -> Result<TransferredNft, CudosError> {
+    parse_reply(reply)
+}
+
+pub fn parse_transfer_denom_reply(reply: &Reply) -> Result<TransferredDenom, CudosError> {
+    parse_reply(reply)
+}
+
+pub fn parse_burn_nft_reply(reply: &Reply) -> Result<BurnedNft, CudosError> {
+    parse_reply(reply)
+}
+
+pub fn parse_approve_nft_reply(reply: &Reply) -> Result<ApprovedNft, CudosError> {
+    parse_reply(reply)
+}
+
+pub fn parse_approve_all_reply(reply: &Reply) -> Result<ApprovedAll, CudosError> {
+    parse_reply(reply)
+}
+
+pub fn parse_revoke_approval_reply(reply: &Reply) -> Result<RevokedNft, CudosError> {
+    parse_reply(reply)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cosmwasm_std::from_slice;
+
+    // Replies in the JSON format wasmd passes to `reply`. wasmd drops the `message` events of
+    // the submessage, leaving the events of the module and of any contract it called.
+    const ISSUE_DENOM: &str = r#"{"id":1,"result":{"ok":{"events":[
+        {"type":"issue_denom","attributes":[
+            {"key":"denom_id","value":"kittens"},
+            {"key":"denom_name","value":"Kittens"},
+            {"key":"creator","value":"cudos14hj2tavq8fpesdwxxcu44rty3hh90vhujrvcmstl4zr3txmfvw9s5kv9zm"}]}],
+        "data":null}}}"#;
+    const MINT_NFT: &str = r#"{"id":2,"result":{"ok":{"events":[
+        {"type":"mint_nft","attributes":[
+            {"key":"token_id","value":"7"},
+            {"key":"denom_id","value":"kittens"},
+            {"key":"token_uri","value":"ipfs://QmZ4tDuvesekSs4qM5ZBKpXiZGun7S2CYtEZRB3DYXkjGx"},
+            {"key":"recipient","value":"cudos1qy352eufqy352eufqy352eufqy352euf3vkc8a"}]}],
+        "data":null}}}"#;
+    const EDIT_NFT: &str = r#"{"id":3,"result":{"ok":{"events":[
+        {"type":"edit_nft","attributes":[
+            {"key":"token_id","value":"7"},
+            {"key":"denom_id","value":"kittens"},
+            {"key":"token_uri","value":""},
+            {"key":"owner","value":"cudos1qy352eufqy352eufqy352eufqy352euf3vkc8a"}]}],
+        "data":null}}}"#;
+    const TRANSFER_NFT: &str = r#"{"id":4,"result":{"ok":{"events":[
+        {"type":"transfer_nft","attributes":[
+            {"key":"token_id","value":"7"},
+            {"key":"denom_id","value":"kittens"},
+            {"key":"from","value":"cudos1qy352eufqy352eufqy352eufqy352euf3vkc8a"},
+            {"key":"to","value":"cudos1ghekyjucln7y67ntx7cf27m9dpuxxemnqk82wt"}]}],
+        "data":null}}}"#;
+    const TRANSFER_DENOM: &str = r#"{"id":5,"result":{"ok":{"events":[
+        {"type":"transfer_denom","attributes":[
+            {"key":"denom_id","value":"kittens"},
+            {"key":"sender","value":"cudos14hj2tavq8fpesdwxxcu44rty3hh90vhujrvcmstl4zr3txmfvw9s5kv9zm"},
+            {"key":"recipient","value":"cudos1ghekyjucln7y67ntx7cf27m9dpuxxemnqk82wt"}]}],
+        "data":null}}}"#;
+    const BURN_NFT: &str = r#"{"id":6,"result":{"ok":{"events":[
+        {"type":"burn_nft","attributes":[
+            {"key":"denom_id","value":"kittens"},
+            {"key":"token_id","value":"7"},
+            {"key":"owner","value":"cudos1qy352eufqy352eufqy352eufqy352euf3vkc8a"}]}],
+        "data":null}}}"#;
+    const APPROVE_NFT: &str = r#"{"id":7,"result":{"ok":{"events":[
+        {"type":"approve_nft","attributes":[
+            {"key":"denom_id","value":"kittens"},
+            {"key":"token_id","value":"7"},
+            {"key":"sender","value":"cudos1qy352eufqy352eufqy352eufqy352euf3vkc8a"},
+            {"key":"approved_address","value":"cudos14hj2tavq8fpesdwxxcu44rty3hh90vhujrvcmstl4zr3txmfvw9s5kv9zm"}]}],
+        "data":null}}}"#;
+    const APPROVE_ALL: &str = r#"{"id":8,"result":{"ok":{"events":[
+        {"type":"approve_all","attributes":[
+            {"key":"sender","value":"cudos1qy352eufqy352eufqy352eufqy352euf3vkc8a"},
+            {"key":"operator","value":"cudos14hj2tavq8fpesdwxxcu44rty3hh90vhujrvcmstl4zr3txmfvw9s5kv9zm"},
+            {"key":"approved","value":"false"}]}],
+        "data":null}}}"#;
+    const REVOKE_NFT: &str = r#"{"id":9,"result":{"ok":{"events":[
+        {"type":"revoke_nft","attributes":[
+            {"key":"denom_id","value":"kittens"},
+            {"key":"token_id","value":"7"},
+            {"key":"sender","value":"cudos1qy352eufqy352eufqy352eufqy352euf3vkc8a"},
+            {"key":"revoked_address","value":"cudos14hj2tavq8fpesdwxxcu44rty3hh90vhujrvcmstl4zr3txmfvw9s5kv9zm"}]}],
+        "data":null}}}"#;
+    // A mint by a contract that was called in the same submessage, whose events come first.
+    const MINT_NFT_AFTER_CONTRACT: &str = r#"{"id":10,"result":{"ok":{"events":[
+        {"type":"execute","attributes":[
+            {"key":"_contract_address","value":"cudos14hj2tavq8fpesdwxxcu44rty3hh90vhujrvcmstl4zr3txmfvw9s5kv9zm"}]},
+        {"type":"wasm","attributes":[
+            {"key":"_contract_address","value":"cudos14hj2tavq8fpesdwxxcu44rty3hh90vhujrvcmstl4zr3txmfvw9s5kv9zm"},
+            {"key":"action","value":"mint"}]},
+        {"type":"mint_nft","attributes":[
+            {"key":"token_id","value":"8"},
+            {"key":"denom_id","value":"kittens"},
+            {"key":"token_uri","value":""},
+            {"key":"recipient","value":"cudos1qy352eufqy352eufqy352eufqy352euf3vkc8a"}]}],
+        "data":null}}}"#;
+    // The mint above without the `recipient` attribute.
+    const MINT_NFT_MISSING_ATTRIBUTE: &str = r#"{"id":11,"result":{"ok":{"events":[
+        {"type":"mint_nft","attributes":[
+            {"key":"token_id","value":"7"},
+            {"key":"denom_id","value":"kittens"},
+            {"key":"token_uri","value":""}]}],
+        "data":null}}}"#;
+    const APPROVE_ALL_NOT_BOOL: &str = r#"{"id":12,"result":{"ok":{"events":[
+        {"type":"approve_all","attributes":[
+            {"key":"sender","value":"cudos1qy352eufqy352eufqy352eufqy352euf3vkc8a"},
+            {"key":"operator","value":"cudos14hj2tavq8fpesdwxxcu44rty3hh90vhujrvcmstl4zr3txmfvw9s5kv9zm"},
+            {"key":"approved","value":"yes"}]}],
+        "data":null}}}"#;
+    // Submessage errors as redacted by wasmd.
+    const UNKNOWN_NFT: &str = r#"{"id":13,"result":{"error":"codespace: nft, code: 13"}}"#;
+    const UNAUTHORIZED: &str = r#"{"id":14,"result":{"error":"codespace: nft, code: 15"}}"#;
+    const DUPLICATE_DENOM: &str = r#"{"id":16,"result":{"error":"codespace: nft, code: 16"}}"#;
+    const OUT_OF_GAS: &str = r#"{"id":15,"result":{"error":"codespace: sdk, code: 11"}}"#;
+
+    const OWNER: &str = "cudos1qy352eufqy352eufqy352eufqy352euf3vkc8a";
+    const CONTRACT: &str = "cudos14hj2tavq8fpesdwxxcu44rty3hh90vhujrvcmstl4zr3txmfvw9s5kv9zm";
+    const RECIPIENT: &str = "cudos1ghekyjucln7y67ntx7cf27m9dpuxxemnqk82wt";
+
+    fn reply(fixture: &str) -> Reply {
+        from_slice(fixture.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn parses_issue_denom_reply() {
+        assert_eq!(
+            parse_issue_denom_reply(&reply(ISSUE_DENOM)).unwrap(),
+            IssuedDenom {
+                denom_id: "kittens".to_string(),
+                denom_name: "Kittens".to_string(),
+                creator: CONTRACT.to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_mint_nft_reply() {
+        assert_eq!(
+            parse_mint_nft_reply(&reply(MINT_NFT)).unwrap(),
+            MintedNft {
+                denom_id: "kittens".to_string(),
+                token_id: "7".to_string(),
+                token_uri: "ipfs://QmZ4tDuvesekSs4qM5ZBKpXiZGun7S2CYtEZRB3DYXkjGx".to_string(),
+                recipient: OWNER.to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_edit_nft_reply() {
+        assert_eq!(
+            parse_edit_nft_reply(&reply(EDIT_NFT)).unwrap(),
+            EditedNft {
+                denom_id: "kittens".to_string(),
+                token_id: "7".to_string(),
+                token_uri: String::new(),
+                owner: OWNER.to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_transfer_nft_reply() {
+        assert_eq!(
+            parse_transfer_nft_reply(&reply(TRANSFER_NFT)).unwrap(),
+            TransferredNft {
+                denom_id: "kittens".to_string(),
+                token_id: "7".to_string(),
+                from: OWNER.to_string(),
+                to: RECIPIENT.to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_transfer_denom_reply() {
+        assert_eq!(
+            parse_transfer_denom_reply(&reply(TRANSFER_DENOM)).unwrap(),
+            TransferredDenom {
+                denom_id: "kittens".to_string(),
+                sender: CONTRACT.to_string(),
+                recipient: RECIPIENT.to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_burn_nft_reply() {
+        assert_eq!(
+            parse_burn_nft_reply(&reply(BURN_NFT)).unwrap(),
+            BurnedNft {
+                denom_id: "kittens".to_string(),
+                token_id: "7".to_string(),
+                owner: OWNER.to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_approve_nft_reply() {
+        assert_eq!(
+            parse_approve_nft_reply(&reply(APPROVE_NFT)).unwrap(),
+            ApprovedNft {
+                denom_id: "kittens".to_string(),
+                token_id: "7".to_string(),
+                sender: OWNER.to_string(),
+                approved_address: CONTRACT.to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_approve_all_reply() {
+        assert_eq!(
+            parse_approve_all_reply(&reply(APPROVE_ALL)).unwrap(),
+            ApprovedAll {
+                sender: OWNER.to_string(),
+                operator: CONTRACT.to_string(),
+                approved: false,
+            }
+        );
+    }
+
+    #[test]
+    fn parses_revoke_approval_reply() {
+        assert_eq!(
+            parse_revoke_approval_reply(&reply(REVOKE_NFT)).unwrap(),
+            RevokedNft {
+                denom_id: "kittens".to_string(),
+                token_id: "7".to_string(),
+                sender: OWNER.to_string(),
+                revoked_address: CONTRACT.to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn skips_events_of_other_types() {
+        let minted = parse_mint_nft_reply(&reply(MINT_NFT_AFTER_CONTRACT)).unwrap();
+        assert_eq!(minted.token_id, "8");
+    }
+
+    #[test]
+    fn rejects_reply_without_the_event() {
+        let err = parse_burn_nft_reply(&reply(MINT_NFT)).unwrap_err();
+        assert_eq!(
+            err,
+            CudosError::InvalidReply("reply 2 has no `burn_nft` event".to_string())
+        );
+        let err = parse_transfer_nft_reply(&reply(TRANSFER_DENOM)).unwrap_err();
+        assert_eq!(
+            err,
+            CudosError::InvalidReply("reply 5 has no `transfer_nft` event".to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_missing_attribute() {
+        let err = parse_mint_nft_reply(&reply(MINT_NFT_MISSING_ATTRIBUTE)).unwrap_err();
+        assert_eq!(
+            err,
+            CudosError::InvalidEvent("`mint_nft` event has no `recipient` attribute".to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_attribute() {
+        let err = parse_approve_all_reply(&reply(APPROVE_ALL_NOT_BOOL)).unwrap_err();
+        assert!(matches!(err, CudosError::InvalidEvent(_)));
+    }
+
+    #[test]
+    fn classifies_failed_submessage() {
+        let err = parse_transfer_nft_reply(&reply(UNKNOWN_NFT)).unwrap_err();
+        assert_eq!(
+            err,
+            CudosError::TokenNotFound("codespace: nft, code: 13".to_string())
+        );
+        let err = parse_burn_nft_reply(&reply(UNAUTHORIZED)).unwrap_err();
+        assert!(matches!(err, CudosError::Unauthorized(_)));
+        // A duplicated denom is redacted to the code of `invalid denom`, not to a missing one.
+        let err = parse_issue_denom_reply(&reply(DUPLICATE_DENOM)).unwrap_err();
+        assert_eq!(
+            err,
+            CudosError::InvalidDenom("codespace: nft, code: 16".to_string())
+        );
+        let err = parse_mint_nft_reply(&reply(OUT_OF_GAS)).unwrap_err();
+        assert!(matches!(err, CudosError::Std(_)));
+    }
+}
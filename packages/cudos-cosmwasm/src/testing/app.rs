@@ -0,0 +1,855 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use cosmwasm_std::testing::{mock_env, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{
+    from_slice, to_binary, to_vec, Addr, Binary, BlockInfo, CosmosMsg, Deps, DepsMut, Env, Event,
+    MessageInfo, Order, Querier, QuerierResult, QuerierWrapper, QueryRequest, Reply, ReplyOn,
+    Response, StdError, StdResult, Storage, SubMsg, SubMsgExecutionResponse, SubMsgResult,
+    SystemError, SystemResult, WasmMsg, WasmQuery,
+};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::msg::CudosMsg;
+use crate::query::CudosQuery;
+use crate::testing::nft::{redact_module_error, redacted, MockNftModule};
+
+type ContractFn<T, E> =
+    fn(DepsMut<CudosQuery>, Env, MessageInfo, T) -> Result<Response<CudosMsg>, E>;
+type QueryFn<T, E> = fn(Deps<CudosQuery>, Env, T) -> Result<Binary, E>;
+type ReplyFn<E> = fn(DepsMut<CudosQuery>, Env, Reply) -> Result<Response<CudosMsg>, E>;
+
+type BoxedContractFn =
+    Box<dyn Fn(DepsMut<CudosQuery>, Env, MessageInfo, &[u8]) -> StdResult<Response<CudosMsg>>>;
+type BoxedQueryFn = Box<dyn Fn(Deps<CudosQuery>, Env, &[u8]) -> StdResult<Binary>>;
+type BoxedReplyFn = Box<dyn Fn(DepsMut<CudosQuery>, Env, Reply) -> StdResult<Response<CudosMsg>>>;
+
+/// Wraps the entry points of a contract so `CudosApp` can call them with raw JSON messages.
+pub struct ContractWrapper {
+    execute: BoxedContractFn,
+    instantiate: BoxedContractFn,
+    query: BoxedQueryFn,
+    reply: Option<BoxedReplyFn>,
+}
+
+impl ContractWrapper {
+    pub fn new<T1, T2, T3, E1, E2, E3>(
+        execute: ContractFn<T1, E1>,
+        instantiate: ContractFn<T2, E2>,
+        query: QueryFn<T3, E3>,
+    ) -> Self
+    where
+        T1: DeserializeOwned + 'static,
+        T2: DeserializeOwned + 'static,
+        T3: DeserializeOwned + 'static,
+        E1: Display + 'static,
+        E2: Display + 'static,
+        E3: Display + 'static,
+    {
+        ContractWrapper {
+            execute: Box::new(move |deps, env, info, msg| {
+                execute(deps, env, info, from_slice(msg)?).map_err(contract_error)
+            }),
+            instantiate: Box::new(move |deps, env, info, msg| {
+                instantiate(deps, env, info, from_slice(msg)?).map_err(contract_error)
+            }),
+            query: Box::new(move |deps, env, msg| {
+                query(deps, env, from_slice(msg)?).map_err(contract_error)
+            }),
+            reply: None,
+        }
+    }
+
+    pub fn with_reply<E4>(mut self, reply: ReplyFn<E4>) -> Self
+    where
+        E4: Display + 'static,
+    {
+        self.reply = Some(Box::new(move |deps, env, msg| {
+            reply(deps, env, msg).map_err(contract_error)
+        }));
+        self
+    }
+}
+
+fn contract_error(err: impl Display) -> StdError {
+    StdError::generic_err(err.to_string())
+}
+
+/// Events and data produced by executing a message in `CudosApp`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AppResponse {
+    pub events: Vec<Event>,
+    pub data: Option<Binary>,
+}
+
+impl AppResponse {
+    /// Returns the first event of type `ty`, if any.
+    pub fn event(&self, ty: &str) -> Option<&Event> {
+        self.events.iter().find(|event| event.ty == ty)
+    }
+}
+
+struct ContractData {
+    code_id: u64,
+    storage: MockStorage,
+}
+
+impl Clone for ContractData {
+    fn clone(&self) -> Self {
+        let mut storage = MockStorage::new();
+        for (key, value) in self.storage.range(None, None, Order::Ascending) {
+            storage.set(&key, &value);
+        }
+        ContractData {
+            code_id: self.code_id,
+            storage,
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+struct AppState {
+    nft: MockNftModule,
+    contracts: BTreeMap<String, ContractData>,
+    contract_count: u64,
+}
+
+/// Minimal chain emulator that runs contracts against an in-memory Cudos NFT module.
+///
+/// `CudosMsg`s returned by contracts are executed with the same authorization rules as the
+/// node, `WasmMsg::Execute` and `WasmMsg::Instantiate` are dispatched to other stored
+/// contracts, and submessage replies are delivered. All state changes of a failed call are
+/// rolled back. Bank and staking messages are not supported.
+pub struct CudosApp {
+    api: MockApi,
+    block: BlockInfo,
+    codes: Vec<ContractWrapper>,
+    state: AppState,
+}
+
+impl Default for CudosApp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CudosApp {
+    pub fn new() -> Self {
+        CudosApp {
+            api: MockApi::default(),
+            block: mock_env().block,
+            codes: vec![],
+            state: AppState::default(),
+        }
+    }
+
+    pub fn block_info(&self) -> BlockInfo {
+        self.block.clone()
+    }
+
+    pub fn update_block<F: Fn(&mut BlockInfo)>(&mut self, action: F) {
+        action(&mut self.block);
+    }
+
+    pub fn nft(&self) -> &MockNftModule {
+        &self.state.nft
+    }
+
+    pub fn nft_mut(&mut self) -> &mut MockNftModule {
+        &mut self.state.nft
+    }
+
+    /// Registers a contract and returns its code id.
+    pub fn store_code(&mut self, code: ContractWrapper) -> u64 {
+        self.codes.push(code);
+        self.codes.len() as u64
+    }
+
+    pub fn instantiate_contract<T: Serialize>(
+        &mut self,
+        code_id: u64,
+        sender: Addr,
+        msg: &T,
+        label: &str,
+    ) -> StdResult<Addr> {
+        let msg = to_vec(msg)?;
+        self.transactional(|app| app.instantiate(sender, code_id, &msg, label))
+            .map(|(address, _)| address)
+    }
+
+    pub fn execute_contract<T: Serialize>(
+        &mut self,
+        sender: Addr,
+        contract_addr: Addr,
+        msg: &T,
+    ) -> StdResult<AppResponse> {
+        let msg = to_vec(msg)?;
+        self.transactional(|app| app.execute(sender, contract_addr, &msg))
+    }
+
+    /// Executes a `CudosMsg` directly against the NFT module, as if it was signed by its
+    /// `sender`.
+    pub fn execute_cudos(&mut self, msg: CudosMsg) -> StdResult<AppResponse> {
+        self.transactional(|app| app.execute_nft(&msg))
+    }
+
+    pub fn query_wasm_smart<T: DeserializeOwned, U: Serialize>(
+        &self,
+        contract_addr: impl Into<String>,
+        msg: &U,
+    ) -> StdResult<T> {
+        let response = self.query_contract(&contract_addr.into(), &to_vec(msg)?)?;
+        from_slice(&response)
+    }
+
+    pub fn query_cudos<T: DeserializeOwned>(&self, request: &CudosQuery) -> StdResult<T> {
+        from_slice(&self.state.nft.query(request)?)
+    }
+
+    fn transactional<F, T>(&mut self, action: F) -> StdResult<T>
+    where
+        F: FnOnce(&mut Self) -> StdResult<T>,
+    {
+        let snapshot = self.state.clone();
+        let result = action(self);
+        if result.is_err() {
+            self.state = snapshot;
+        }
+        result
+    }
+
+    fn env(&self, contract_addr: &str) -> Env {
+        let mut env = mock_env();
+        env.block = self.block.clone();
+        env.contract.address = Addr::unchecked(contract_addr);
+        env
+    }
+
+    fn with_contract<F, T>(&mut self, contract_addr: &str, action: F) -> StdResult<T>
+    where
+        F: FnOnce(&ContractWrapper, DepsMut<CudosQuery>, Env) -> StdResult<T>,
+    {
+        let mut data = self
+            .state
+            .contracts
+            .remove(contract_addr)
+            .ok_or_else(|| no_such_contract(contract_addr))?;
+
+        let result = {
+            let querier = AppQuerier { app: self };
+            let deps = DepsMut {
+                storage: &mut data.storage,
+                api: &self.api,
+                querier: QuerierWrapper::new(&querier),
+            };
+            action(
+                &self.codes[data.code_id as usize - 1],
+                deps,
+                self.env(contract_addr),
+            )
+        };
+
+        self.state.contracts.insert(contract_addr.to_string(), data);
+        result
+    }
+
+    fn query_contract(&self, contract_addr: &str, msg: &[u8]) -> StdResult<Binary> {
+        let data = self
+            .state
+            .contracts
+            .get(contract_addr)
+            .ok_or_else(|| no_such_contract(contract_addr))?;
+        let querier = AppQuerier { app: self };
+        let deps = Deps {
+            storage: &data.storage,
+            api: &self.api,
+            querier: QuerierWrapper::new(&querier),
+        };
+        (self.codes[data.code_id as usize - 1].query)(deps, self.env(contract_addr), msg)
+    }
+
+    fn instantiate(
+        &mut self,
+        sender: Addr,
+        code_id: u64,
+        msg: &[u8],
+        label: &str,
+    ) -> StdResult<(Addr, AppResponse)> {
+        if code_id == 0 || code_id as usize > self.codes.len() {
+            return Err(StdError::generic_err(format!("no such code: {}", code_id)));
+        }
+
+        let address = format!("contract{}", self.state.contract_count);
+        self.state.contract_count += 1;
+        self.state.contracts.insert(
+            address.clone(),
+            ContractData {
+                code_id,
+                storage: MockStorage::new(),
+            },
+        );
+
+        let info = message_info(sender);
+        let response = self.with_contract(&address, |code, deps, env| {
+            (code.instantiate)(deps, env, info, msg)
+        })?;
+        let event = Event::new("instantiate")
+            .add_attribute("_contract_address", &address)
+            .add_attribute("code_id", code_id.to_string())
+            .add_attribute("label", label);
+        let response = self.process_response(&address, response, event)?;
+
+        Ok((Addr::unchecked(address), response))
+    }
+
+    fn execute(&mut self, sender: Addr, contract_addr: Addr, msg: &[u8]) -> StdResult<AppResponse> {
+        let info = message_info(sender);
+        let response = self.with_contract(contract_addr.as_str(), |code, deps, env| {
+            (code.execute)(deps, env, info, msg)
+        })?;
+        let event =
+            Event::new("execute").add_attribute("_contract_address", contract_addr.as_str());
+        self.process_response(contract_addr.as_str(), response, event)
+    }
+
+    fn execute_nft(&mut self, msg: &CudosMsg) -> StdResult<AppResponse> {
+        let event = self.state.nft.execute(msg)?;
+        Ok(AppResponse {
+            events: vec![event],
+            data: None,
+        })
+    }
+
+    fn process_response(
+        &mut self,
+        contract_addr: &str,
+        response: Response<CudosMsg>,
+        event: Event,
+    ) -> StdResult<AppResponse> {
+        let mut events = vec![event];
+        if !response.attributes.is_empty() {
+            events.push(
+                Event::new("wasm")
+                    .add_attribute("_contract_address", contract_addr)
+                    .add_attributes(response.attributes),
+            );
+        }
+        events.extend(response.events.into_iter().map(|event| {
+            Event::new(format!("wasm-{}", event.ty))
+                .add_attribute("_contract_address", contract_addr)
+                .add_attributes(event.attributes)
+        }));
+
+        let mut data = response.data;
+        for msg in response.messages {
+            let response = self.execute_submsg(contract_addr, msg)?;
+            events.extend(response.events);
+            if response.data.is_some() {
+                data = response.data;
+            }
+        }
+
+        Ok(AppResponse { events, data })
+    }
+
+    fn execute_submsg(
+        &mut self,
+        contract_addr: &str,
+        msg: SubMsg<CudosMsg>,
+    ) -> StdResult<AppResponse> {
+        let SubMsg {
+            id, msg, reply_on, ..
+        } = msg;
+
+        let redact = submsg_error_redactor(&msg);
+        let result = self.transactional(|app| app.dispatch(contract_addr, msg));
+        let result = match (result, &reply_on) {
+            (Ok(response), ReplyOn::Always) | (Ok(response), ReplyOn::Success) => {
+                SubMsgResult::Ok(SubMsgExecutionResponse {
+                    events: response.events,
+                    data: response.data,
+                })
+            }
+            // Like wasmd, the reply only carries the codespace and code of the error.
+            (Err(err), ReplyOn::Always) | (Err(err), ReplyOn::Error) => {
+                SubMsgResult::Err(redact(&err))
+            }
+            (Ok(response), _) => {
+                return Ok(AppResponse {
+                    events: response.events,
+                    data: None,
+                })
+            }
+            (Err(err), _) => return Err(err),
+        };
+
+        let mut events = match &result {
+            SubMsgResult::Ok(response) => response.events.clone(),
+            SubMsgResult::Err(_) => vec![],
+        };
+        let reply = Reply { id, result };
+        let response = self.with_contract(contract_addr, |code, deps, env| match &code.reply {
+            Some(reply_fn) => reply_fn(deps, env, reply),
+            None => Err(StdError::generic_err("contract does not implement reply")),
+        })?;
+        let event = Event::new("reply").add_attribute("_contract_address", contract_addr);
+        let response = self.process_response(contract_addr, response, event)?;
+        events.extend(response.events);
+
+        Ok(AppResponse {
+            events,
+            data: response.data,
+        })
+    }
+
+    fn dispatch(
+        &mut self,
+        contract_addr: &str,
+        msg: CosmosMsg<CudosMsg>,
+    ) -> StdResult<AppResponse> {
+        match msg {
+            CosmosMsg::Custom(msg) => {
+                let signer = contract_address_signer(&msg);
+                if signer != contract_addr {
+                    return Err(StdError::generic_err(format!(
+                        "contract_address_signer {} does not match the executing contract {}",
+                        signer, contract_addr
+                    )));
+                }
+                self.execute_nft(&msg)
+            }
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: target,
+                msg,
+                ..
+            }) => self.execute(
+                Addr::unchecked(contract_addr),
+                Addr::unchecked(target),
+                &msg,
+            ),
+            CosmosMsg::Wasm(WasmMsg::Instantiate {
+                code_id,
+                msg,
+                label,
+                ..
+            }) => {
+                let (address, mut response) =
+                    self.instantiate(Addr::unchecked(contract_addr), code_id, &msg, &label)?;
+                response.data = Some(to_binary(&address)?);
+                Ok(response)
+            }
+            msg => Err(StdError::generic_err(format!(
+                "unsupported message: {:?}",
+                msg
+            ))),
+        }
+    }
+}
+
+// Picks how the error of a submessage is redacted: errors of the NFT module keep the code
+// they are registered with, while failed contracts are reported with the codes of wasmd.
+fn submsg_error_redactor(msg: &CosmosMsg<CudosMsg>) -> fn(&StdError) -> String {
+    match msg {
+        CosmosMsg::Custom(_) => redact_module_error,
+        CosmosMsg::Wasm(WasmMsg::Instantiate { .. }) => |_| redacted("wasm", 4),
+        CosmosMsg::Wasm(WasmMsg::Execute { .. }) => |_| redacted("wasm", 5),
+        _ => |_| redacted("wasm", 20),
+    }
+}
+
+fn message_info(sender: Addr) -> MessageInfo {
+    MessageInfo {
+        sender,
+        funds: vec![],
+    }
+}
+
+fn no_such_contract(contract_addr: &str) -> StdError {
+    StdError::generic_err(format!("no such contract: {}", contract_addr))
+}
+
+fn contract_address_signer(msg: &CudosMsg) -> &str {
+    match msg {
+        CudosMsg::IssueDenomMsg {
+            contract_address_signer,
+            ..
+        }
+        | CudosMsg::MintNftMsg {
+            contract_address_signer,
+            ..
+        }
+        | CudosMsg::EditNftMsg {
+            contract_address_signer,
+            ..
+        }
+        | CudosMsg::TransferNftMsg {
+            contract_address_signer,
+            ..
+        }
+        | CudosMsg::TransferDenomMsg {
+            contract_address_signer,
+            ..
+        }
+        | CudosMsg::BurnNftMsg {
+            contract_address_signer,
+            ..
+        }
+        | CudosMsg::ApproveNftMsg {
+            contract_address_signer,
+            ..
+        }
+        | CudosMsg::ApproveAllMsg {
+            contract_address_signer,
+            ..
+        }
+        | CudosMsg::RevokeApprovalMsg {
+            contract_address_signer,
+            ..
+        }
+        | CudosMsg::PublishCollectionMsg {
+            contract_address_signer,
+            ..
+        }
+        | CudosMsg::UnpublishCollectionMsg {
+            contract_address_signer,
+            ..
+        }
+        | CudosMsg::PublishNftMsg {
+            contract_address_signer,
+            ..
+        }
+        | CudosMsg::UnpublishNftMsg {
+            contract_address_signer,
+            ..
+        }
+        | CudosMsg::BuyNftMsg {
+            contract_address_signer,
+            ..
+        }
+        | CudosMsg::UpdatePriceMsg {
+            contract_address_signer,
+            ..
+        }
+        | CudosMsg::MarketplaceMintNftMsg {
+            contract_address_signer,
+            ..
+        }
+        | CudosMsg::CreateAddressMsg {
+            contract_address_signer,
+            ..
+        }
+        | CudosMsg::UpdateAddressMsg {
+            contract_address_signer,
+            ..
+        }
+        | CudosMsg::DeleteAddressMsg {
+            contract_address_signer,
+            ..
+        }
+        | CudosMsg::SendToEthMsg {
+            contract_address_signer,
+            ..
+        }
+        | CudosMsg::CancelSendToEthMsg {
+            contract_address_signer,
+            ..
+        } => contract_address_signer,
+    }
+}
+
+// Querier handed to contracts running inside the app. A contract cannot query itself while
+// it is executing.
+struct AppQuerier<'a> {
+    app: &'a CudosApp,
+}
+
+impl Querier for AppQuerier<'_> {
+    fn raw_query(&self, bin_request: &[u8]) -> QuerierResult {
+        let request: QueryRequest<CudosQuery> = match from_slice(bin_request) {
+            Ok(v) => v,
+            Err(e) => {
+                return SystemResult::Err(SystemError::InvalidRequest {
+                    error: format!("Parsing query request: {}", e),
+                    request: bin_request.into(),
+                })
+            }
+        };
+
+        // Like wasmd, failed queries only return the codespace and code of their error.
+        let result = match request {
+            QueryRequest::Custom(query) => self
+                .app
+                .state
+                .nft
+                .query(&query)
+                .map_err(|err| redact_module_error(&err)),
+            QueryRequest::Wasm(WasmQuery::Smart { contract_addr, msg }) => {
+                if !self.app.state.contracts.contains_key(&contract_addr) {
+                    return SystemResult::Err(SystemError::NoSuchContract {
+                        addr: contract_addr,
+                    });
+                }
+                self.app
+                    .query_contract(&contract_addr, &msg)
+                    .map_err(|_| redacted("wasm", 9))
+            }
+            request => return MockQuerier::<CudosQuery>::new(&[]).handle_query(&request),
+        };
+
+        SystemResult::Ok(result.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cosmwasm_std::Empty;
+    use serde::Deserialize;
+
+    use crate::capability::CudosCapability;
+    use crate::error::CudosError;
+    use crate::msg::create_issue_denom_msg;
+    use crate::querier::CudosQuerier;
+    use crate::query::NFT;
+    use crate::reply::{parse_issue_denom_reply, parse_transfer_nft_reply};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    enum ExecuteMsg {
+        // Sends `msg` as a submessage and stores the error of its reply.
+        Send { msg: Box<CosmosMsg<CudosMsg>> },
+        Fail {},
+    }
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    enum QueryMsg {
+        ReplyError {},
+        Token { denom_id: String, token_id: String },
+        TokenOpt { denom_id: String, token_id: String },
+        Supports { capability: CudosCapability },
+    }
+
+    fn instantiate(
+        _deps: DepsMut<CudosQuery>,
+        _env: Env,
+        _info: MessageInfo,
+        _msg: Empty,
+    ) -> StdResult<Response<CudosMsg>> {
+        Ok(Response::new())
+    }
+
+    fn execute(
+        _deps: DepsMut<CudosQuery>,
+        _env: Env,
+        _info: MessageInfo,
+        msg: ExecuteMsg,
+    ) -> StdResult<Response<CudosMsg>> {
+        match msg {
+            ExecuteMsg::Send { msg } => {
+                Ok(Response::new().add_submessage(SubMsg::reply_on_error(*msg, 1)))
+            }
+            ExecuteMsg::Fail {} => Err(StdError::generic_err("failed on purpose")),
+        }
+    }
+
+    fn reply(deps: DepsMut<CudosQuery>, _env: Env, reply: Reply) -> StdResult<Response<CudosMsg>> {
+        let err = parse_transfer_nft_reply(&reply).unwrap_err();
+        let msg = match reply.result {
+            SubMsgResult::Err(msg) => msg,
+            SubMsgResult::Ok(_) => unreachable!(),
+        };
+        deps.storage.set(b"error", &to_vec(&msg)?);
+        Ok(Response::new().add_attribute("not_found", err.is_not_found().to_string()))
+    }
+
+    fn query(deps: Deps<CudosQuery>, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+        let querier = CudosQuerier::new(&deps.querier);
+        match msg {
+            QueryMsg::ReplyError {} => Ok(deps.storage.get(b"error").unwrap_or_default().into()),
+            QueryMsg::Token { denom_id, token_id } => {
+                to_binary(&querier.query_token(denom_id, token_id)?.nft)
+            }
+            QueryMsg::TokenOpt { denom_id, token_id } => {
+                to_binary(&querier.query_token_opt(denom_id, token_id)?)
+            }
+            QueryMsg::Supports { capability } => to_binary(&querier.supports(capability)),
+        }
+    }
+
+    fn setup() -> (CudosApp, Addr) {
+        let mut app = CudosApp::new();
+        app.nft_mut()
+            .issue_denom("kittens", "Kittens", "KIT", None, "creator")
+            .unwrap();
+        app.nft_mut()
+            .mint_nft("kittens", "first", None, None, "owner")
+            .unwrap();
+        let code_id =
+            app.store_code(ContractWrapper::new(execute, instantiate, query).with_reply(reply));
+        let contract = app
+            .instantiate_contract(code_id, Addr::unchecked("creator"), &Empty {}, "test")
+            .unwrap();
+        (app, contract)
+    }
+
+    fn transfer(token_id: &str, contract: &Addr) -> CosmosMsg<CudosMsg> {
+        CudosMsg::TransferNftMsg {
+            denom_id: "kittens".to_string(),
+            token_id: token_id.to_string(),
+            from: "owner".to_string(),
+            to: "recipient".to_string(),
+            sender: "owner".to_string(),
+            contract_address_signer: contract.to_string(),
+        }
+        .into()
+    }
+
+    fn send(app: &mut CudosApp, contract: &Addr, msg: CosmosMsg<CudosMsg>) -> String {
+        let res = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                contract.clone(),
+                &ExecuteMsg::Send { msg: Box::new(msg) },
+            )
+            .unwrap();
+        assert!(res.event("reply").is_some());
+        app.query_wasm_smart(contract, &QueryMsg::ReplyError {})
+            .unwrap()
+    }
+
+    #[test]
+    fn redacts_errors_of_the_module_in_replies() {
+        let (mut app, contract) = setup();
+        let msg = transfer("2", &contract);
+        assert_eq!(send(&mut app, &contract, msg), "codespace: nft, code: 13");
+
+        // The parsers of the contract still classify the redacted error.
+        let msg = transfer("2", &contract);
+        let res = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                contract.clone(),
+                &ExecuteMsg::Send { msg: Box::new(msg) },
+            )
+            .unwrap();
+        let wasm = res.event("wasm").unwrap();
+        assert_eq!(wasm.attributes[1].value, "true");
+
+        let msg = CudosMsg::BurnNftMsg {
+            denom_id: "kittens".to_string(),
+            token_id: "1".to_string(),
+            sender: "stranger".to_string(),
+            contract_address_signer: contract.to_string(),
+        }
+        .into();
+        assert_eq!(send(&mut app, &contract, msg), "codespace: nft, code: 15");
+
+        let msg = CudosMsg::BurnNftMsg {
+            denom_id: "Kittens".to_string(),
+            token_id: "1".to_string(),
+            sender: "owner".to_string(),
+            contract_address_signer: contract.to_string(),
+        }
+        .into();
+        assert_eq!(send(&mut app, &contract, msg), "codespace: nft, code: 16");
+    }
+
+    #[test]
+    fn duplicated_denoms_are_not_reported_as_missing() {
+        let (mut app, contract) = setup();
+        let msg = create_issue_denom_msg(
+            "kittens".to_string(),
+            "Kittens".to_string(),
+            "KIT".to_string(),
+            None,
+            "owner".to_string(),
+            contract.to_string(),
+        );
+        let error = send(&mut app, &contract, msg);
+        assert_eq!(error, "codespace: nft, code: 16");
+
+        let reply = Reply {
+            id: 1,
+            result: SubMsgResult::Err(error),
+        };
+        let err = parse_issue_denom_reply(&reply).unwrap_err();
+        assert!(matches!(err, CudosError::InvalidDenom(_)));
+    }
+
+    #[test]
+    fn redacts_errors_of_contracts_in_replies() {
+        let (mut app, contract) = setup();
+        let msg = WasmMsg::Execute {
+            contract_addr: contract.to_string(),
+            msg: to_binary(&ExecuteMsg::Fail {}).unwrap(),
+            funds: vec![],
+        }
+        .into();
+        assert_eq!(send(&mut app, &contract, msg), "codespace: wasm, code: 5");
+    }
+
+    #[test]
+    fn redacts_errors_of_queries() {
+        let (app, contract) = setup();
+        let err = app
+            .query_wasm_smart::<NFT, _>(
+                &contract,
+                &QueryMsg::Token {
+                    denom_id: "kittens".to_string(),
+                    token_id: "2".to_string(),
+                },
+            )
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .ends_with("Querier contract error: codespace: nft, code: 13"));
+        assert!(CudosError::from(err).is_not_found());
+
+        let nft: Option<NFT> = app
+            .query_wasm_smart(
+                &contract,
+                &QueryMsg::TokenOpt {
+                    denom_id: "kittens".to_string(),
+                    token_id: "2".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(nft, None);
+    }
+
+    #[test]
+    fn contracts_detect_the_emulated_modules() {
+        let (app, contract) = setup();
+        let supports = |capability| -> bool {
+            app.query_wasm_smart(&contract, &QueryMsg::Supports { capability })
+                .unwrap()
+        };
+        assert!(supports(CudosCapability::Nft));
+        assert!(!supports(CudosCapability::Marketplace));
+        assert!(!supports(CudosCapability::Gravity));
+        assert!(!supports(CudosCapability::Mint));
+    }
+
+    #[test]
+    fn keeps_the_message_of_failed_calls() {
+        let (mut app, contract) = setup();
+        let err = app
+            .execute_contract(Addr::unchecked("owner"), contract, &ExecuteMsg::Fail {})
+            .unwrap_err();
+        assert!(err.to_string().ends_with("failed on purpose"));
+
+        let err = app
+            .execute_cudos(CudosMsg::BurnNftMsg {
+                denom_id: "kittens".to_string(),
+                token_id: "1".to_string(),
+                sender: "stranger".to_string(),
+                contract_address_signer: "stranger".to_string(),
+            })
+            .unwrap_err();
+        assert!(err.to_string().ends_with(": unauthorized address"));
+        assert!(app.nft().nft("kittens", "1").is_ok());
+    }
+}
@@ -0,0 +1,25 @@
+mod app;
+mod nft;
+mod querier;
+
+use std::marker::PhantomData;
+
+use cosmwasm_std::testing::{MockApi, MockStorage};
+use cosmwasm_std::OwnedDeps;
+
+use crate::query::CudosQuery;
+
+pub use app::{AppResponse, ContractWrapper, CudosApp};
+pub use nft::MockNftModule;
+pub use querier::CudosMockQuerier;
+
+/// Creates all external requirements that can be injected for unit tests of contracts
+/// using `CudosQuery`. Seed the NFT module state through `deps.querier.nft`.
+pub fn mock_cudos_dependencies() -> OwnedDeps<MockStorage, MockApi, CudosMockQuerier, CudosQuery> {
+    OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier: CudosMockQuerier::default(),
+        custom_query_type: PhantomData,
+    }
+}
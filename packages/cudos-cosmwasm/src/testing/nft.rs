@@ -0,0 +1,1519 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use cosmwasm_std::{to_binary, Binary, Event, StdError, StdResult};
+
+use crate::error::{
+    CODE_INVALID_DENOM, CODE_NFT_ALREADY_EXISTS, CODE_UNAUTHORIZED, CODE_UNKNOWN_COLLECTION,
+    CODE_UNKNOWN_NFT, NFT_CODESPACE,
+};
+use crate::events::{
+    ATTRIBUTE_KEY_APPROVED, ATTRIBUTE_KEY_APPROVED_ADDRESS, ATTRIBUTE_KEY_CREATOR,
+    ATTRIBUTE_KEY_DENOM_ID, ATTRIBUTE_KEY_DENOM_NAME, ATTRIBUTE_KEY_FROM, ATTRIBUTE_KEY_OPERATOR,
+    ATTRIBUTE_KEY_OWNER, ATTRIBUTE_KEY_RECIPIENT, ATTRIBUTE_KEY_REVOKED_ADDRESS,
+    ATTRIBUTE_KEY_SENDER, ATTRIBUTE_KEY_TO, ATTRIBUTE_KEY_TOKEN_ID, ATTRIBUTE_KEY_TOKEN_URI,
+    EVENT_TYPE_APPROVE_ALL, EVENT_TYPE_APPROVE_NFT, EVENT_TYPE_BURN_NFT, EVENT_TYPE_EDIT_NFT,
+    EVENT_TYPE_ISSUE_DENOM, EVENT_TYPE_MINT_NFT, EVENT_TYPE_REVOKE_NFT, EVENT_TYPE_TRANSFER_DENOM,
+    EVENT_TYPE_TRANSFER_NFT,
+};
+use crate::msg::CudosMsg;
+use crate::query::{
+    Collection, CollectionResponse, CudosQuery, Denom, DenomResponse, DenomsResponse, IDCollection,
+    Owner, OwnerCollectionResponse, PageResponse, PaginationRequest, QueryApprovalsResponse,
+    QueryApprovedForAllResponse, QueryNFTResponse, SupplyResponse, NFT,
+};
+use crate::validation::ValidationError;
+
+// Default page size used by the cosmos-sdk when no limit is provided.
+const DEFAULT_PAGE_LIMIT: u64 = 100;
+
+// Errors returned by the module, as the codespace, code and description they are registered
+// with in x/nft/types/errors.go and, for addresses, in the cosmos-sdk.
+const REGISTERED_ERRORS: &[(&str, u32, &str)] = &[
+    (NFT_CODESPACE, 9, "invalid nft collection"),
+    (
+        NFT_CODESPACE,
+        CODE_UNKNOWN_COLLECTION,
+        "unknown nft collection",
+    ),
+    (NFT_CODESPACE, 11, "invalid nft"),
+    (NFT_CODESPACE, CODE_NFT_ALREADY_EXISTS, "nft already exists"),
+    (NFT_CODESPACE, CODE_UNKNOWN_NFT, "unknown nft"),
+    (NFT_CODESPACE, 14, "nft data can't be empty"),
+    (NFT_CODESPACE, CODE_UNAUTHORIZED, "unauthorized address"),
+    (NFT_CODESPACE, CODE_INVALID_DENOM, "invalid denom"),
+    (NFT_CODESPACE, 17, "invalid nft id"),
+    (NFT_CODESPACE, 18, "invalid nft uri"),
+    ("sdk", 7, "invalid address"),
+];
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct MockDenom {
+    denom: Denom,
+    nfts: BTreeMap<String, NFT>,
+    last_token_id: u64,
+}
+
+/// In-memory emulation of the Cudos NFT module state.
+///
+/// Answers the queries of the NFT module the same way the node does, so contracts using
+/// `CudosQuerier` can be unit tested without a running `cudos-noded`. Messages and queries of
+/// the other modules are rejected.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MockNftModule {
+    denoms: BTreeMap<String, MockDenom>,
+    operators: BTreeSet<(String, String)>,
+}
+
+impl MockNftModule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stores a new denom owned by `creator`. Ids, names and symbols must be unique.
+    pub fn issue_denom(
+        &mut self,
+        id: impl Into<String>,
+        name: impl Into<String>,
+        symbol: impl Into<String>,
+        schema: Option<String>,
+        creator: impl Into<String>,
+    ) -> StdResult<()> {
+        self.insert_denom(Denom {
+            id: id.into(),
+            name: name.into(),
+            symbol: symbol.into(),
+            schema,
+            creator: creator.into(),
+            ..Denom::default()
+        })
+    }
+
+    /// Stores `denom` with all of its metadata. Ids, names and symbols must be unique.
+    pub fn insert_denom(&mut self, denom: Denom) -> StdResult<()> {
+        if self.denoms.contains_key(&denom.id) {
+            return Err(module_error(
+                format!("denomID {} has already exists", denom.id),
+                "invalid denom",
+            ));
+        }
+        if self.denoms.values().any(|d| d.denom.name == denom.name) {
+            return Err(module_error(
+                format!("denomName {} has already exists", denom.name),
+                "invalid denom",
+            ));
+        }
+        if self.denoms.values().any(|d| d.denom.symbol == denom.symbol) {
+            return Err(module_error(
+                format!("denomSymbol {} has already exists", denom.symbol),
+                "invalid denom",
+            ));
+        }
+
+        self.denoms.insert(
+            denom.id.clone(),
+            MockDenom {
+                denom,
+                ..MockDenom::default()
+            },
+        );
+        Ok(())
+    }
+
+    /// Mints a new NFT to `owner` and returns the token id assigned to it.
+    pub fn mint_nft(
+        &mut self,
+        denom_id: &str,
+        name: impl Into<String>,
+        uri: Option<String>,
+        data: Option<String>,
+        owner: impl Into<String>,
+    ) -> StdResult<String> {
+        let denom = self.denom_mut(denom_id)?;
+        denom.last_token_id += 1;
+        let token_id = denom.last_token_id.to_string();
+
+        let nft = NFT {
+            id: token_id.clone(),
+            name: Some(name.into()),
+            uri,
+            data,
+            owner: owner.into(),
+            approved_addresses: None,
+        };
+        denom.nfts.insert(token_id.clone(), nft);
+        Ok(token_id)
+    }
+
+    /// Adds `address` to the approved addresses of the given NFT.
+    pub fn approve_nft(&mut self, denom_id: &str, token_id: &str, address: &str) -> StdResult<()> {
+        let nft = self.nft_mut(denom_id, token_id)?;
+        let approved = nft.approved_addresses.get_or_insert_with(Vec::new);
+        if !approved.iter().any(|a| a == address) {
+            approved.push(address.to_string());
+        }
+        Ok(())
+    }
+
+    /// Sets or clears `operator` as an approved operator for all NFTs of `owner`.
+    pub fn set_approved_for_all(&mut self, owner: &str, operator: &str, approved: bool) {
+        let key = (owner.to_string(), operator.to_string());
+        if approved {
+            self.operators.insert(key);
+        } else {
+            self.operators.remove(&key);
+        }
+    }
+
+    /// Executes `msg` on behalf of its `sender`, enforcing the same authorization rules as
+    /// the node, and returns the event the module emits for it.
+    pub fn execute(&mut self, msg: &CudosMsg) -> StdResult<Event> {
+        msg.validate().map_err(invalid_msg)?;
+
+        match msg {
+            CudosMsg::IssueDenomMsg {
+                id,
+                name,
+                symbol,
+                schema,
+                description,
+                uri,
+                uri_hash,
+                data,
+                traits,
+                minter,
+                sender,
+                ..
+            } => {
+                self.insert_denom(Denom {
+                    id: id.clone(),
+                    name: name.clone(),
+                    schema: schema.clone(),
+                    creator: sender.clone(),
+                    symbol: symbol.clone(),
+                    description: description.clone(),
+                    uri: uri.clone(),
+                    uri_hash: uri_hash.clone(),
+                    data: data.clone(),
+                    traits: traits.clone(),
+                    minter: minter.clone(),
+                })?;
+                Ok(Event::new(EVENT_TYPE_ISSUE_DENOM)
+                    .add_attribute(ATTRIBUTE_KEY_DENOM_ID, id)
+                    .add_attribute(ATTRIBUTE_KEY_DENOM_NAME, name)
+                    .add_attribute(ATTRIBUTE_KEY_CREATOR, sender))
+            }
+            CudosMsg::MintNftMsg {
+                denom_id,
+                name,
+                uri,
+                data,
+                sender,
+                recipient,
+                ..
+            } => {
+                let denom = self.denom(denom_id)?;
+                if &denom.creator != sender && denom.minter.as_ref() != Some(sender) {
+                    return Err(unauthorized(format!(
+                        "{} is not allowed to mint NFT of denom {}",
+                        sender, denom_id
+                    )));
+                }
+                let token_id =
+                    self.mint_nft(denom_id, name, uri.clone(), data.clone(), recipient)?;
+                Ok(Event::new(EVENT_TYPE_MINT_NFT)
+                    .add_attribute(ATTRIBUTE_KEY_TOKEN_ID, token_id)
+                    .add_attribute(ATTRIBUTE_KEY_DENOM_ID, denom_id)
+                    .add_attribute(ATTRIBUTE_KEY_TOKEN_URI, uri.clone().unwrap_or_default())
+                    .add_attribute(ATTRIBUTE_KEY_RECIPIENT, recipient))
+            }
+            CudosMsg::EditNftMsg {
+                denom_id,
+                token_id,
+                name,
+                uri,
+                data,
+                sender,
+                ..
+            } => {
+                let nft = self.nft_mut(denom_id, token_id)?;
+                if &nft.owner != sender {
+                    return Err(not_owner(sender, denom_id, token_id));
+                }
+                if let Some(name) = name {
+                    nft.name = Some(name.clone());
+                }
+                if let Some(uri) = uri {
+                    nft.uri = Some(uri.clone());
+                }
+                if let Some(data) = data {
+                    nft.data = Some(data.clone());
+                }
+                Ok(Event::new(EVENT_TYPE_EDIT_NFT)
+                    .add_attribute(ATTRIBUTE_KEY_TOKEN_ID, token_id)
+                    .add_attribute(ATTRIBUTE_KEY_DENOM_ID, denom_id)
+                    .add_attribute(ATTRIBUTE_KEY_TOKEN_URI, nft.uri.clone().unwrap_or_default())
+                    .add_attribute(ATTRIBUTE_KEY_OWNER, sender))
+            }
+            CudosMsg::TransferNftMsg {
+                denom_id,
+                token_id,
+                from,
+                to,
+                sender,
+                ..
+            } => {
+                let nft = self.nft(denom_id, token_id)?;
+                if &nft.owner != from {
+                    return Err(not_owner(from, denom_id, token_id));
+                }
+                self.assert_approved_or_owner(denom_id, token_id, sender)?;
+
+                let nft = self.nft_mut(denom_id, token_id)?;
+                nft.owner = to.clone();
+                nft.approved_addresses = None;
+                Ok(Event::new(EVENT_TYPE_TRANSFER_NFT)
+                    .add_attribute(ATTRIBUTE_KEY_TOKEN_ID, token_id)
+                    .add_attribute(ATTRIBUTE_KEY_DENOM_ID, denom_id)
+                    .add_attribute(ATTRIBUTE_KEY_FROM, from)
+                    .add_attribute(ATTRIBUTE_KEY_TO, to))
+            }
+            CudosMsg::TransferDenomMsg {
+                denom_id,
+                to,
+                sender,
+                ..
+            } => {
+                let denom = self.denom_mut(denom_id)?;
+                if &denom.denom.creator != sender {
+                    return Err(unauthorized(format!(
+                        "{} is not allowed to transfer denom {}",
+                        sender, denom_id
+                    )));
+                }
+                denom.denom.creator = to.clone();
+                Ok(Event::new(EVENT_TYPE_TRANSFER_DENOM)
+                    .add_attribute(ATTRIBUTE_KEY_DENOM_ID, denom_id)
+                    .add_attribute(ATTRIBUTE_KEY_SENDER, sender)
+                    .add_attribute(ATTRIBUTE_KEY_RECIPIENT, to))
+            }
+            CudosMsg::BurnNftMsg {
+                denom_id,
+                token_id,
+                sender,
+                ..
+            } => {
+                self.assert_approved_or_owner(denom_id, token_id, sender)?;
+                let owner = self.denom_mut(denom_id)?.nfts.remove(token_id);
+                Ok(Event::new(EVENT_TYPE_BURN_NFT)
+                    .add_attribute(ATTRIBUTE_KEY_DENOM_ID, denom_id)
+                    .add_attribute(ATTRIBUTE_KEY_TOKEN_ID, token_id)
+                    .add_attribute(
+                        ATTRIBUTE_KEY_OWNER,
+                        owner.map(|nft| nft.owner).unwrap_or_default(),
+                    ))
+            }
+            CudosMsg::ApproveNftMsg {
+                denom_id,
+                token_id,
+                approved_address,
+                sender,
+                ..
+            } => {
+                self.assert_owner_or_operator(denom_id, token_id, sender)?;
+                self.approve_nft(denom_id, token_id, approved_address)?;
+                Ok(Event::new(EVENT_TYPE_APPROVE_NFT)
+                    .add_attribute(ATTRIBUTE_KEY_DENOM_ID, denom_id)
+                    .add_attribute(ATTRIBUTE_KEY_TOKEN_ID, token_id)
+                    .add_attribute(ATTRIBUTE_KEY_SENDER, sender)
+                    .add_attribute(ATTRIBUTE_KEY_APPROVED_ADDRESS, approved_address))
+            }
+            CudosMsg::ApproveAllMsg {
+                approved_operator,
+                approved,
+                sender,
+                ..
+            } => {
+                self.set_approved_for_all(sender, approved_operator, *approved);
+                Ok(Event::new(EVENT_TYPE_APPROVE_ALL)
+                    .add_attribute(ATTRIBUTE_KEY_SENDER, sender)
+                    .add_attribute(ATTRIBUTE_KEY_OPERATOR, approved_operator)
+                    .add_attribute(ATTRIBUTE_KEY_APPROVED, approved.to_string()))
+            }
+            CudosMsg::RevokeApprovalMsg {
+                denom_id,
+                token_id,
+                address_to_revoke,
+                sender,
+                ..
+            } => {
+                self.assert_owner_or_operator(denom_id, token_id, sender)?;
+                let nft = self.nft_mut(denom_id, token_id)?;
+                let approved = nft.approved_addresses.get_or_insert_with(Vec::new);
+                let len = approved.len();
+                approved.retain(|a| a != address_to_revoke);
+                if approved.len() == len {
+                    return Err(unauthorized(format!(
+                        "{} is not approved for {}/{}",
+                        address_to_revoke, denom_id, token_id
+                    )));
+                }
+                if approved.is_empty() {
+                    nft.approved_addresses = None;
+                }
+                Ok(Event::new(EVENT_TYPE_REVOKE_NFT)
+                    .add_attribute(ATTRIBUTE_KEY_DENOM_ID, denom_id)
+                    .add_attribute(ATTRIBUTE_KEY_TOKEN_ID, token_id)
+                    .add_attribute(ATTRIBUTE_KEY_SENDER, sender)
+                    .add_attribute(ATTRIBUTE_KEY_REVOKED_ADDRESS, address_to_revoke))
+            }
+            CudosMsg::PublishCollectionMsg { .. }
+            | CudosMsg::UnpublishCollectionMsg { .. }
+            | CudosMsg::PublishNftMsg { .. }
+            | CudosMsg::UnpublishNftMsg { .. }
+            | CudosMsg::BuyNftMsg { .. }
+            | CudosMsg::UpdatePriceMsg { .. }
+            | CudosMsg::MarketplaceMintNftMsg { .. } => Err(not_emulated("marketplace")),
+            CudosMsg::CreateAddressMsg { .. }
+            | CudosMsg::UpdateAddressMsg { .. }
+            | CudosMsg::DeleteAddressMsg { .. } => Err(not_emulated("addressbook")),
+            CudosMsg::SendToEthMsg { .. } | CudosMsg::CancelSendToEthMsg { .. } => {
+                Err(not_emulated("gravity bridge"))
+            }
+        }
+    }
+
+    pub fn denom(&self, denom_id: &str) -> StdResult<&Denom> {
+        self.denoms
+            .get(denom_id)
+            .map(|d| &d.denom)
+            .ok_or_else(|| denom_not_found("denomID", denom_id))
+    }
+
+    pub fn nft(&self, denom_id: &str, token_id: &str) -> StdResult<&NFT> {
+        self.denoms
+            .get(denom_id)
+            .and_then(|d| d.nfts.get(token_id))
+            .ok_or_else(|| nft_not_found(denom_id, token_id))
+    }
+
+    pub fn is_approved_for_all(&self, owner: &str, operator: &str) -> bool {
+        self.operators
+            .contains(&(owner.to_string(), operator.to_string()))
+    }
+
+    pub fn query(&self, request: &CudosQuery) -> StdResult<Binary> {
+        match request {
+            CudosQuery::QueryDenomById { denom_id } => to_binary(&DenomResponse {
+                denom: self.denom(denom_id)?.clone(),
+            }),
+            CudosQuery::QueryDenomByName { denom_name } => {
+                let denom = self
+                    .denoms
+                    .values()
+                    .find(|d| &d.denom.name == denom_name)
+                    .ok_or_else(|| denom_not_found("denomName", denom_name))?;
+                to_binary(&DenomResponse {
+                    denom: denom.denom.clone(),
+                })
+            }
+            CudosQuery::QueryDenomBySymbol { denom_symbol } => {
+                let denom = self
+                    .denoms
+                    .values()
+                    .find(|d| &d.denom.symbol == denom_symbol)
+                    .ok_or_else(|| denom_not_found("denomSymbol", denom_symbol))?;
+                to_binary(&DenomResponse {
+                    denom: denom.denom.clone(),
+                })
+            }
+            CudosQuery::QueryDenoms { pagination } => {
+                let items = self
+                    .denoms
+                    .iter()
+                    .map(|(id, d)| (id.clone(), d.denom.clone()));
+                let (denoms, pagination) = paginate(items, pagination.as_ref())?;
+                to_binary(&DenomsResponse {
+                    denoms: Some(denoms),
+                    pagination: Some(pagination),
+                })
+            }
+            CudosQuery::QueryCollection {
+                denom_id,
+                pagination,
+            } => {
+                let denom = self.denom_ref(denom_id)?;
+                let items = denom.nfts.iter().map(|(id, nft)| (id.clone(), nft.clone()));
+                let (nfts, pagination) = paginate(items, pagination.as_ref())?;
+                to_binary(&CollectionResponse {
+                    collection: Some(Collection {
+                        denom: denom.denom.clone(),
+                        nfts: Some(nfts),
+                    }),
+                    pagination: Some(pagination),
+                })
+            }
+            CudosQuery::QuerySupply { denom_id } => to_binary(&SupplyResponse {
+                amount: self.denom_ref(denom_id)?.nfts.len() as u64,
+            }),
+            CudosQuery::QueryOwner {
+                denom_id,
+                address,
+                pagination,
+            } => {
+                if let Some(denom_id) = denom_id {
+                    self.denom_ref(denom_id)?;
+                }
+                let items =
+                    self.denoms
+                        .iter()
+                        .filter(|(id, _)| match denom_id {
+                            Some(denom_id) => denom_id == *id,
+                            None => true,
+                        })
+                        .flat_map(|(id, denom)| {
+                            denom.nfts.values().filter(|nft| &nft.owner == address).map(
+                                move |nft| {
+                                    // The store prefix covers the denom when it is given, so
+                                    // the keys are only the token ids.
+                                    let key = match denom_id {
+                                        Some(_) => nft.id.clone(),
+                                        None => format!("{}/{}", id, nft.id),
+                                    };
+                                    (key, (id.clone(), nft.id.clone()))
+                                },
+                            )
+                        });
+                let (tokens, pagination) = paginate(items, pagination.as_ref())?;
+
+                let mut id_collections: Vec<IDCollection> = vec![];
+                for (denom_id, token_id) in tokens {
+                    match id_collections.last_mut() {
+                        Some(collection) if collection.denom_id == denom_id => {
+                            collection.token_ids.push(token_id)
+                        }
+                        _ => id_collections.push(IDCollection {
+                            denom_id,
+                            token_ids: vec![token_id],
+                        }),
+                    }
+                }
+
+                to_binary(&OwnerCollectionResponse {
+                    owner: Owner {
+                        address: address.clone(),
+                        id_collections,
+                    },
+                    pagination: Some(pagination),
+                })
+            }
+            CudosQuery::QueryToken { denom_id, token_id } => to_binary(&QueryNFTResponse {
+                nft: self.nft(denom_id, token_id)?.clone(),
+            }),
+            CudosQuery::QueryApprovals { denom_id, token_id } => {
+                let nft = self.nft(denom_id, token_id)?;
+                to_binary(&QueryApprovalsResponse {
+                    approved_addresses: nft.approved_addresses.clone().unwrap_or_default(),
+                })
+            }
+            CudosQuery::QueryApprovedForAll {
+                owner_address,
+                operator_address,
+            } => to_binary(&QueryApprovedForAllResponse {
+                is_approved: self.is_approved_for_all(owner_address, operator_address),
+            }),
+            CudosQuery::QueryMarketplaceCollection { .. }
+            | CudosQuery::QueryMarketplaceCollectionByDenomId { .. }
+            | CudosQuery::QueryMarketplaceCollections { .. }
+            | CudosQuery::QueryListing { .. }
+            | CudosQuery::QueryListings { .. } => Err(not_emulated("marketplace")),
+            CudosQuery::QueryAddress { .. } => Err(not_emulated("addressbook")),
+            CudosQuery::QueryPendingBatches {}
+            | CudosQuery::QueryBatchFees {}
+            | CudosQuery::QueryLastObservedEthNonce {}
+            | CudosQuery::QueryErc20ToDenom { .. }
+            | CudosQuery::QueryDenomToErc20 { .. } => Err(not_emulated("gravity bridge")),
+            CudosQuery::MintParams {} | CudosQuery::AnnualProvisions {} => {
+                Err(not_emulated("cudoMint"))
+            }
+        }
+    }
+
+    fn assert_owner_or_operator(
+        &self,
+        denom_id: &str,
+        token_id: &str,
+        sender: &str,
+    ) -> StdResult<()> {
+        let nft = self.nft(denom_id, token_id)?;
+        if nft.owner == sender || self.is_approved_for_all(&nft.owner, sender) {
+            Ok(())
+        } else {
+            Err(not_owner(sender, denom_id, token_id))
+        }
+    }
+
+    fn assert_approved_or_owner(
+        &self,
+        denom_id: &str,
+        token_id: &str,
+        sender: &str,
+    ) -> StdResult<()> {
+        let nft = self.nft(denom_id, token_id)?;
+        let approved = nft
+            .approved_addresses
+            .iter()
+            .flatten()
+            .any(|address| address == sender);
+        if approved {
+            Ok(())
+        } else {
+            self.assert_owner_or_operator(denom_id, token_id, sender)
+        }
+    }
+
+    fn denom_ref(&self, denom_id: &str) -> StdResult<&MockDenom> {
+        self.denoms
+            .get(denom_id)
+            .ok_or_else(|| denom_not_found("denomID", denom_id))
+    }
+
+    fn denom_mut(&mut self, denom_id: &str) -> StdResult<&mut MockDenom> {
+        self.denoms
+            .get_mut(denom_id)
+            .ok_or_else(|| denom_not_found("denomID", denom_id))
+    }
+
+    fn nft_mut(&mut self, denom_id: &str, token_id: &str) -> StdResult<&mut NFT> {
+        self.denoms
+            .get_mut(denom_id)
+            .and_then(|d| d.nfts.get_mut(token_id))
+            .ok_or_else(|| nft_not_found(denom_id, token_id))
+    }
+}
+
+// Mirrors the `sdkerrors.Wrapf` formatting used by the module, e.g.
+// "not found denomID: testdenom: invalid denom".
+fn module_error(context: String, reason: &str) -> StdError {
+    StdError::generic_err(format!("{}: {}", context, reason))
+}
+
+fn denom_not_found(field: &str, value: &str) -> StdError {
+    module_error(format!("not found {}: {}", field, value), "invalid denom")
+}
+
+fn nft_not_found(denom_id: &str, token_id: &str) -> StdError {
+    module_error(
+        format!("not found NFT: {}, tokenID: {}", denom_id, token_id),
+        "unknown nft",
+    )
+}
+
+fn unauthorized(context: String) -> StdError {
+    module_error(context, "unauthorized address")
+}
+
+fn not_owner(address: &str, denom_id: &str, token_id: &str) -> StdError {
+    unauthorized(format!(
+        "{} is not the owner of {}/{}",
+        address, denom_id, token_id
+    ))
+}
+
+// The node rejects invalid messages in their `ValidateBasic`, with the registered error of
+// the invalid field.
+fn invalid_msg(err: ValidationError) -> StdError {
+    let reason = match err {
+        ValidationError::InvalidAddress { .. } => "invalid address",
+        ValidationError::InvalidTokenId { .. } => "invalid nft id",
+        ValidationError::InvalidUri { .. } => "invalid nft uri",
+        _ => "invalid denom",
+    };
+    module_error(err.to_string(), reason)
+}
+
+/// Redacts an error of the module the way wasmd does before a contract sees it, keeping only
+/// the codespace and code it is registered with. Other errors are internal errors of the sdk.
+pub(crate) fn redact_module_error(err: &StdError) -> String {
+    let msg = match err {
+        StdError::GenericErr { msg, .. } => msg.clone(),
+        err => err.to_string(),
+    };
+    let (codespace, code) = REGISTERED_ERRORS
+        .iter()
+        .find(|(_, _, reason)| msg.ends_with(&format!(": {}", reason)))
+        .map_or(("undefined", 1), |(codespace, code, _)| (*codespace, *code));
+    redacted(codespace, code)
+}
+
+pub(crate) fn redacted(codespace: &str, code: u32) -> String {
+    format!("codespace: {}, code: {}", codespace, code)
+}
+
+fn not_emulated(module: &str) -> StdError {
+    StdError::generic_err(format!(
+        "the {} module is not emulated by MockNftModule",
+        module
+    ))
+}
+
+// Pages through `items` like the cosmos-sdk `Paginate` helper iterating over a prefix store.
+// Items are ordered by their store key, and the keys cross the bindings base64 encoded.
+fn paginate<T>(
+    items: impl Iterator<Item = (String, T)>,
+    pagination: Option<&PaginationRequest>,
+) -> StdResult<(Vec<T>, PageResponse)> {
+    let mut items: Vec<(String, T)> = items.collect();
+    let request = pagination.cloned().unwrap_or(PaginationRequest {
+        key: None,
+        offset: None,
+        limit: None,
+        count_total: None,
+        reverse: None,
+    });
+    let key = match request.key.as_deref() {
+        Some(key) if !key.is_empty() => Some(Binary::from_base64(key)?),
+        _ => None,
+    };
+
+    if key.is_some() && request.offset.is_some() {
+        return Err(StdError::generic_err(
+            "either offset or key is expected, got both: invalid request",
+        ));
+    }
+    items.sort_by(|(a, _), (b, _)| a.cmp(b));
+    let reverse = request.reverse.unwrap_or(false);
+    if reverse {
+        items.reverse();
+    }
+    let total = items.len() as u64;
+
+    // A key starts the page at the first item at or after it in the order of iteration.
+    let start = match (&key, request.offset) {
+        (Some(key), _) => items
+            .iter()
+            .position(|(k, _)| {
+                let k = k.as_bytes();
+                if reverse {
+                    k <= key.as_slice()
+                } else {
+                    k >= key.as_slice()
+                }
+            })
+            .unwrap_or(items.len()),
+        (None, Some(offset)) => (offset as usize).min(items.len()),
+        (None, None) => 0,
+    };
+    let limit = match request.limit {
+        Some(limit) if limit > 0 => limit as usize,
+        _ => DEFAULT_PAGE_LIMIT as usize,
+    };
+    let end = start.saturating_add(limit).min(items.len());
+
+    let next_key = items
+        .get(end)
+        .map(|(k, _)| Binary::from(k.as_bytes()).to_base64());
+    let count_total = key.is_none() && request.count_total.unwrap_or(false);
+    let page = items.drain(start..end).map(|(_, item)| item).collect();
+
+    Ok((
+        page,
+        PageResponse {
+            next_key,
+            total: if count_total { Some(total) } else { None },
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cosmwasm_std::from_binary;
+    use serde::de::DeserializeOwned;
+
+    use crate::error::CudosError;
+
+    const CREATOR: &str = "creator";
+    const MINTER: &str = "minter";
+    const OWNER: &str = "owner";
+    const APPROVED: &str = "approved";
+    const OPERATOR: &str = "operator";
+    const STRANGER: &str = "stranger";
+
+    // A module with the denom "kittens" of CREATOR, minted to by MINTER, and token "1" of
+    // OWNER approved for APPROVED.
+    fn setup() -> MockNftModule {
+        let mut module = MockNftModule::new();
+        module
+            .insert_denom(Denom {
+                id: "kittens".to_string(),
+                name: "Kittens".to_string(),
+                symbol: "KIT".to_string(),
+                creator: CREATOR.to_string(),
+                minter: Some(MINTER.to_string()),
+                ..Denom::default()
+            })
+            .unwrap();
+        module
+            .mint_nft("kittens", "first", None, None, OWNER)
+            .unwrap();
+        module.approve_nft("kittens", "1", APPROVED).unwrap();
+        module
+    }
+
+    fn query<T: DeserializeOwned>(module: &MockNftModule, request: CudosQuery) -> T {
+        from_binary(&module.query(&request).unwrap()).unwrap()
+    }
+
+    fn classify(err: StdError) -> CudosError {
+        err.into()
+    }
+
+    fn mint(sender: &str) -> CudosMsg {
+        CudosMsg::MintNftMsg {
+            denom_id: "kittens".to_string(),
+            name: "second".to_string(),
+            uri: Some("ipfs://second".to_string()),
+            data: None,
+            sender: sender.to_string(),
+            contract_address_signer: sender.to_string(),
+            recipient: OWNER.to_string(),
+        }
+    }
+
+    fn transfer(from: &str, sender: &str) -> CudosMsg {
+        CudosMsg::TransferNftMsg {
+            denom_id: "kittens".to_string(),
+            token_id: "1".to_string(),
+            from: from.to_string(),
+            to: STRANGER.to_string(),
+            sender: sender.to_string(),
+            contract_address_signer: sender.to_string(),
+        }
+    }
+
+    fn burn(token_id: &str, sender: &str) -> CudosMsg {
+        CudosMsg::BurnNftMsg {
+            denom_id: "kittens".to_string(),
+            token_id: token_id.to_string(),
+            sender: sender.to_string(),
+            contract_address_signer: sender.to_string(),
+        }
+    }
+
+    fn approve(approved_address: &str, sender: &str) -> CudosMsg {
+        CudosMsg::ApproveNftMsg {
+            denom_id: "kittens".to_string(),
+            token_id: "1".to_string(),
+            approved_address: approved_address.to_string(),
+            sender: sender.to_string(),
+            contract_address_signer: sender.to_string(),
+        }
+    }
+
+    fn revoke(address_to_revoke: &str, sender: &str) -> CudosMsg {
+        CudosMsg::RevokeApprovalMsg {
+            denom_id: "kittens".to_string(),
+            token_id: "1".to_string(),
+            address_to_revoke: address_to_revoke.to_string(),
+            sender: sender.to_string(),
+            contract_address_signer: sender.to_string(),
+        }
+    }
+
+    fn approve_all(sender: &str, approved: bool) -> CudosMsg {
+        CudosMsg::ApproveAllMsg {
+            approved_operator: OPERATOR.to_string(),
+            approved,
+            sender: sender.to_string(),
+            contract_address_signer: sender.to_string(),
+        }
+    }
+
+    fn page(key: Option<String>, offset: Option<u64>, limit: u64) -> Option<PaginationRequest> {
+        Some(PaginationRequest {
+            key,
+            offset,
+            limit: Some(limit),
+            count_total: None,
+            reverse: None,
+        })
+    }
+
+    #[test]
+    fn issue_denom() {
+        let mut module = setup();
+        let event = module
+            .execute(&CudosMsg::IssueDenomMsg {
+                id: "puppies".to_string(),
+                name: "Puppies".to_string(),
+                symbol: "PUP".to_string(),
+                schema: None,
+                description: Some("Good dogs".to_string()),
+                uri: None,
+                uri_hash: None,
+                data: None,
+                traits: None,
+                minter: None,
+                sender: CREATOR.to_string(),
+                contract_address_signer: CREATOR.to_string(),
+            })
+            .unwrap();
+        assert_eq!(
+            event,
+            Event::new(EVENT_TYPE_ISSUE_DENOM)
+                .add_attribute(ATTRIBUTE_KEY_DENOM_ID, "puppies")
+                .add_attribute(ATTRIBUTE_KEY_DENOM_NAME, "Puppies")
+                .add_attribute(ATTRIBUTE_KEY_CREATOR, CREATOR)
+        );
+        let denom = module.denom("puppies").unwrap();
+        assert_eq!(denom.creator, CREATOR);
+        assert_eq!(denom.description.as_deref(), Some("Good dogs"));
+    }
+
+    #[test]
+    fn issue_denom_rejects_duplicates() {
+        let mut module = setup();
+        let err = module
+            .issue_denom("kittens", "Other", "OTH", None, CREATOR)
+            .unwrap_err();
+        assert!(matches!(classify(err), CudosError::AlreadyExists(_)));
+        let err = module
+            .issue_denom("other", "Kittens", "OTH", None, CREATOR)
+            .unwrap_err();
+        assert!(matches!(classify(err), CudosError::AlreadyExists(_)));
+        let err = module
+            .issue_denom("other", "Other", "KIT", None, CREATOR)
+            .unwrap_err();
+        assert!(matches!(classify(err), CudosError::AlreadyExists(_)));
+    }
+
+    #[test]
+    fn issue_denom_validates_message() {
+        let mut module = setup();
+        let err = module
+            .execute(&CudosMsg::IssueDenomMsg {
+                id: "Not A Denom".to_string(),
+                name: "Other".to_string(),
+                symbol: "OTH".to_string(),
+                schema: None,
+                description: None,
+                uri: None,
+                uri_hash: None,
+                data: None,
+                traits: None,
+                minter: None,
+                sender: CREATOR.to_string(),
+                contract_address_signer: CREATOR.to_string(),
+            })
+            .unwrap_err();
+        assert!(err.to_string().contains("Not A Denom"));
+        assert!(module.denom("Not A Denom").is_err());
+    }
+
+    #[test]
+    fn mint_by_creator_and_minter() {
+        let mut module = setup();
+        let event = module.execute(&mint(CREATOR)).unwrap();
+        assert_eq!(
+            event,
+            Event::new(EVENT_TYPE_MINT_NFT)
+                .add_attribute(ATTRIBUTE_KEY_TOKEN_ID, "2")
+                .add_attribute(ATTRIBUTE_KEY_DENOM_ID, "kittens")
+                .add_attribute(ATTRIBUTE_KEY_TOKEN_URI, "ipfs://second")
+                .add_attribute(ATTRIBUTE_KEY_RECIPIENT, OWNER)
+        );
+        module.execute(&mint(MINTER)).unwrap();
+
+        let nft = module.nft("kittens", "3").unwrap();
+        assert_eq!(nft.owner, OWNER);
+        assert_eq!(nft.name.as_deref(), Some("second"));
+        assert_eq!(nft.approved_addresses, None);
+    }
+
+    #[test]
+    fn mint_rejects_others() {
+        let mut module = setup();
+        let err = module.execute(&mint(OWNER)).unwrap_err();
+        assert!(matches!(classify(err), CudosError::Unauthorized(_)));
+
+        let err = module
+            .mint_nft("puppies", "first", None, None, OWNER)
+            .unwrap_err();
+        assert!(matches!(classify(err), CudosError::DenomNotFound(_)));
+    }
+
+    #[test]
+    fn edit_by_owner() {
+        let mut module = setup();
+        let event = module
+            .execute(&CudosMsg::EditNftMsg {
+                denom_id: "kittens".to_string(),
+                token_id: "1".to_string(),
+                name: None,
+                uri: Some("ipfs://edited".to_string()),
+                data: Some("{}".to_string()),
+                sender: OWNER.to_string(),
+                contract_address_signer: OWNER.to_string(),
+            })
+            .unwrap();
+        assert_eq!(
+            event,
+            Event::new(EVENT_TYPE_EDIT_NFT)
+                .add_attribute(ATTRIBUTE_KEY_TOKEN_ID, "1")
+                .add_attribute(ATTRIBUTE_KEY_DENOM_ID, "kittens")
+                .add_attribute(ATTRIBUTE_KEY_TOKEN_URI, "ipfs://edited")
+                .add_attribute(ATTRIBUTE_KEY_OWNER, OWNER)
+        );
+
+        let nft = module.nft("kittens", "1").unwrap();
+        assert_eq!(nft.name.as_deref(), Some("first"));
+        assert_eq!(nft.uri.as_deref(), Some("ipfs://edited"));
+        assert_eq!(nft.data.as_deref(), Some("{}"));
+    }
+
+    #[test]
+    fn edit_rejects_others_and_missing_tokens() {
+        let mut module = setup();
+        let edit = |token_id: &str, sender: &str| CudosMsg::EditNftMsg {
+            denom_id: "kittens".to_string(),
+            token_id: token_id.to_string(),
+            name: Some("edited".to_string()),
+            uri: None,
+            data: None,
+            sender: sender.to_string(),
+            contract_address_signer: sender.to_string(),
+        };
+        // Approvals do not extend to editing.
+        let err = module.execute(&edit("1", APPROVED)).unwrap_err();
+        assert!(matches!(classify(err), CudosError::Unauthorized(_)));
+        let err = module.execute(&edit("2", OWNER)).unwrap_err();
+        assert!(matches!(classify(err), CudosError::TokenNotFound(_)));
+        assert_eq!(
+            module.nft("kittens", "1").unwrap().name.as_deref(),
+            Some("first")
+        );
+    }
+
+    #[test]
+    fn transfer_by_owner_approved_and_operator() {
+        for sender in [OWNER, APPROVED, OPERATOR] {
+            let mut module = setup();
+            module.set_approved_for_all(OWNER, OPERATOR, true);
+            let event = module.execute(&transfer(OWNER, sender)).unwrap();
+            assert_eq!(
+                event,
+                Event::new(EVENT_TYPE_TRANSFER_NFT)
+                    .add_attribute(ATTRIBUTE_KEY_TOKEN_ID, "1")
+                    .add_attribute(ATTRIBUTE_KEY_DENOM_ID, "kittens")
+                    .add_attribute(ATTRIBUTE_KEY_FROM, OWNER)
+                    .add_attribute(ATTRIBUTE_KEY_TO, STRANGER)
+            );
+            let nft = module.nft("kittens", "1").unwrap();
+            assert_eq!(nft.owner, STRANGER);
+            // Approvals are cleared with the transfer.
+            assert_eq!(nft.approved_addresses, None);
+        }
+    }
+
+    #[test]
+    fn transfer_rejects_others() {
+        let mut module = setup();
+        let err = module.execute(&transfer(OWNER, STRANGER)).unwrap_err();
+        assert!(matches!(classify(err), CudosError::Unauthorized(_)));
+        // `from` must be the current owner, even when the sender is approved.
+        let err = module.execute(&transfer(STRANGER, APPROVED)).unwrap_err();
+        assert!(matches!(classify(err), CudosError::Unauthorized(_)));
+        assert_eq!(module.nft("kittens", "1").unwrap().owner, OWNER);
+    }
+
+    #[test]
+    fn transfer_denom_by_creator() {
+        let mut module = setup();
+        let transfer_denom = |sender: &str| CudosMsg::TransferDenomMsg {
+            denom_id: "kittens".to_string(),
+            to: STRANGER.to_string(),
+            sender: sender.to_string(),
+            contract_address_signer: sender.to_string(),
+        };
+        let err = module.execute(&transfer_denom(MINTER)).unwrap_err();
+        assert!(matches!(classify(err), CudosError::Unauthorized(_)));
+
+        let event = module.execute(&transfer_denom(CREATOR)).unwrap();
+        assert_eq!(
+            event,
+            Event::new(EVENT_TYPE_TRANSFER_DENOM)
+                .add_attribute(ATTRIBUTE_KEY_DENOM_ID, "kittens")
+                .add_attribute(ATTRIBUTE_KEY_SENDER, CREATOR)
+                .add_attribute(ATTRIBUTE_KEY_RECIPIENT, STRANGER)
+        );
+        assert_eq!(module.denom("kittens").unwrap().creator, STRANGER);
+    }
+
+    #[test]
+    fn burn_by_owner_approved_and_operator() {
+        for sender in [OWNER, APPROVED, OPERATOR] {
+            let mut module = setup();
+            module.set_approved_for_all(OWNER, OPERATOR, true);
+            let event = module.execute(&burn("1", sender)).unwrap();
+            assert_eq!(
+                event,
+                Event::new(EVENT_TYPE_BURN_NFT)
+                    .add_attribute(ATTRIBUTE_KEY_DENOM_ID, "kittens")
+                    .add_attribute(ATTRIBUTE_KEY_TOKEN_ID, "1")
+                    .add_attribute(ATTRIBUTE_KEY_OWNER, OWNER)
+            );
+            let err = module.nft("kittens", "1").unwrap_err();
+            assert!(matches!(classify(err), CudosError::TokenNotFound(_)));
+        }
+    }
+
+    #[test]
+    fn burn_rejects_others_and_missing_tokens() {
+        let mut module = setup();
+        let err = module.execute(&burn("1", STRANGER)).unwrap_err();
+        assert!(matches!(classify(err), CudosError::Unauthorized(_)));
+        let err = module.execute(&burn("2", OWNER)).unwrap_err();
+        assert!(matches!(classify(err), CudosError::TokenNotFound(_)));
+        assert!(module.nft("kittens", "1").is_ok());
+    }
+
+    #[test]
+    fn approve_by_owner_and_operator() {
+        let mut module = setup();
+        let event = module.execute(&approve(STRANGER, OWNER)).unwrap();
+        assert_eq!(
+            event,
+            Event::new(EVENT_TYPE_APPROVE_NFT)
+                .add_attribute(ATTRIBUTE_KEY_DENOM_ID, "kittens")
+                .add_attribute(ATTRIBUTE_KEY_TOKEN_ID, "1")
+                .add_attribute(ATTRIBUTE_KEY_SENDER, OWNER)
+                .add_attribute(ATTRIBUTE_KEY_APPROVED_ADDRESS, STRANGER)
+        );
+        module.set_approved_for_all(OWNER, OPERATOR, true);
+        module.execute(&approve(STRANGER, OPERATOR)).unwrap();
+
+        let approvals: QueryApprovalsResponse = query(
+            &module,
+            CudosQuery::QueryApprovals {
+                denom_id: "kittens".to_string(),
+                token_id: "1".to_string(),
+            },
+        );
+        // Approving an address twice keeps a single entry.
+        assert_eq!(approvals.approved_addresses, vec![APPROVED, STRANGER]);
+    }
+
+    #[test]
+    fn approve_rejects_approved_addresses() {
+        let mut module = setup();
+        let err = module.execute(&approve(STRANGER, APPROVED)).unwrap_err();
+        assert!(matches!(classify(err), CudosError::Unauthorized(_)));
+    }
+
+    #[test]
+    fn approve_all_sets_and_clears_operator() {
+        let mut module = setup();
+        let event = module.execute(&approve_all(OWNER, true)).unwrap();
+        assert_eq!(
+            event,
+            Event::new(EVENT_TYPE_APPROVE_ALL)
+                .add_attribute(ATTRIBUTE_KEY_SENDER, OWNER)
+                .add_attribute(ATTRIBUTE_KEY_OPERATOR, OPERATOR)
+                .add_attribute(ATTRIBUTE_KEY_APPROVED, "true")
+        );
+        let res: QueryApprovedForAllResponse = query(
+            &module,
+            CudosQuery::QueryApprovedForAll {
+                owner_address: OWNER.to_string(),
+                operator_address: OPERATOR.to_string(),
+            },
+        );
+        assert!(res.is_approved);
+        assert!(!module.is_approved_for_all(OPERATOR, OWNER));
+
+        module.execute(&approve_all(OWNER, false)).unwrap();
+        assert!(!module.is_approved_for_all(OWNER, OPERATOR));
+    }
+
+    #[test]
+    fn revoke_by_owner_and_operator() {
+        let mut module = setup();
+        let event = module.execute(&revoke(APPROVED, OWNER)).unwrap();
+        assert_eq!(
+            event,
+            Event::new(EVENT_TYPE_REVOKE_NFT)
+                .add_attribute(ATTRIBUTE_KEY_DENOM_ID, "kittens")
+                .add_attribute(ATTRIBUTE_KEY_TOKEN_ID, "1")
+                .add_attribute(ATTRIBUTE_KEY_SENDER, OWNER)
+                .add_attribute(ATTRIBUTE_KEY_REVOKED_ADDRESS, APPROVED)
+        );
+        // The last revoked approval leaves no list behind.
+        assert_eq!(module.nft("kittens", "1").unwrap().approved_addresses, None);
+
+        module.approve_nft("kittens", "1", APPROVED).unwrap();
+        module.set_approved_for_all(OWNER, OPERATOR, true);
+        module.execute(&revoke(APPROVED, OPERATOR)).unwrap();
+    }
+
+    #[test]
+    fn revoke_rejects_others_and_unknown_approvals() {
+        let mut module = setup();
+        let err = module.execute(&revoke(APPROVED, APPROVED)).unwrap_err();
+        assert!(matches!(classify(err), CudosError::Unauthorized(_)));
+        let err = module.execute(&revoke(STRANGER, OWNER)).unwrap_err();
+        assert!(err.to_string().contains("is not approved"));
+        assert_eq!(
+            module.nft("kittens", "1").unwrap().approved_addresses,
+            Some(vec![APPROVED.to_string()])
+        );
+    }
+
+    #[test]
+    fn other_modules_are_not_emulated() {
+        let mut module = setup();
+        let err = module
+            .execute(&CudosMsg::UnpublishNftMsg {
+                id: 1,
+                sender: OWNER.to_string(),
+                contract_address_signer: OWNER.to_string(),
+            })
+            .unwrap_err();
+        assert!(err.to_string().contains("not emulated"));
+        let err = module.query(&CudosQuery::MintParams {}).unwrap_err();
+        assert!(err.to_string().contains("not emulated"));
+    }
+
+    #[test]
+    fn query_denoms_by_id_name_and_symbol() {
+        let module = setup();
+        for request in [
+            CudosQuery::QueryDenomById {
+                denom_id: "kittens".to_string(),
+            },
+            CudosQuery::QueryDenomByName {
+                denom_name: "Kittens".to_string(),
+            },
+            CudosQuery::QueryDenomBySymbol {
+                denom_symbol: "KIT".to_string(),
+            },
+        ] {
+            let res: DenomResponse = query(&module, request);
+            assert_eq!(res.denom.id, "kittens");
+        }
+
+        for request in [
+            CudosQuery::QueryDenomById {
+                denom_id: "puppies".to_string(),
+            },
+            CudosQuery::QueryDenomByName {
+                denom_name: "Puppies".to_string(),
+            },
+            CudosQuery::QueryDenomBySymbol {
+                denom_symbol: "PUP".to_string(),
+            },
+            CudosQuery::QuerySupply {
+                denom_id: "puppies".to_string(),
+            },
+        ] {
+            let err = module.query(&request).unwrap_err();
+            assert!(matches!(classify(err), CudosError::DenomNotFound(_)));
+        }
+    }
+
+    #[test]
+    fn query_tokens() {
+        let module = setup();
+        let res: QueryNFTResponse = query(
+            &module,
+            CudosQuery::QueryToken {
+                denom_id: "kittens".to_string(),
+                token_id: "1".to_string(),
+            },
+        );
+        assert_eq!(res.nft.owner, OWNER);
+        let res: SupplyResponse = query(
+            &module,
+            CudosQuery::QuerySupply {
+                denom_id: "kittens".to_string(),
+            },
+        );
+        assert_eq!(res.amount, 1);
+
+        for request in [
+            CudosQuery::QueryToken {
+                denom_id: "kittens".to_string(),
+                token_id: "2".to_string(),
+            },
+            CudosQuery::QueryApprovals {
+                denom_id: "kittens".to_string(),
+                token_id: "2".to_string(),
+            },
+        ] {
+            let err = module.query(&request).unwrap_err();
+            assert!(matches!(classify(err), CudosError::TokenNotFound(_)));
+        }
+    }
+
+    #[test]
+    fn query_owner_groups_tokens_by_denom() {
+        let mut module = setup();
+        module
+            .issue_denom("puppies", "Puppies", "PUP", None, CREATOR)
+            .unwrap();
+        module
+            .mint_nft("puppies", "first", None, None, OWNER)
+            .unwrap();
+        module
+            .mint_nft("kittens", "second", None, None, STRANGER)
+            .unwrap();
+        module
+            .mint_nft("kittens", "third", None, None, OWNER)
+            .unwrap();
+
+        let res: OwnerCollectionResponse = query(
+            &module,
+            CudosQuery::QueryOwner {
+                denom_id: None,
+                address: OWNER.to_string(),
+                pagination: None,
+            },
+        );
+        assert_eq!(
+            res.owner.id_collections,
+            vec![
+                IDCollection {
+                    denom_id: "kittens".to_string(),
+                    token_ids: vec!["1".to_string(), "3".to_string()],
+                },
+                IDCollection {
+                    denom_id: "puppies".to_string(),
+                    token_ids: vec!["1".to_string()],
+                },
+            ]
+        );
+
+        let res: OwnerCollectionResponse = query(
+            &module,
+            CudosQuery::QueryOwner {
+                denom_id: Some("puppies".to_string()),
+                address: OWNER.to_string(),
+                pagination: None,
+            },
+        );
+        assert_eq!(res.owner.id_collections.len(), 1);
+
+        let err = module
+            .query(&CudosQuery::QueryOwner {
+                denom_id: Some("birds".to_string()),
+                address: OWNER.to_string(),
+                pagination: None,
+            })
+            .unwrap_err();
+        assert!(matches!(classify(err), CudosError::DenomNotFound(_)));
+    }
+
+    // Token ids are ordered by their bytes in the store, so "10" comes before "2".
+    fn collection_page(
+        module: &MockNftModule,
+        pagination: Option<PaginationRequest>,
+    ) -> (Vec<String>, PageResponse) {
+        let res: CollectionResponse = query(
+            module,
+            CudosQuery::QueryCollection {
+                denom_id: "kittens".to_string(),
+                pagination,
+            },
+        );
+        let ids = res
+            .collection
+            .unwrap()
+            .nfts
+            .unwrap()
+            .into_iter()
+            .map(|nft| nft.id)
+            .collect();
+        (ids, res.pagination.unwrap())
+    }
+
+    fn eleven_tokens() -> MockNftModule {
+        let mut module = setup();
+        for _ in 0..10 {
+            module
+                .mint_nft("kittens", "more", None, None, OWNER)
+                .unwrap();
+        }
+        module
+    }
+
+    #[test]
+    fn pages_follow_next_key() {
+        let module = eleven_tokens();
+        let mut ids = vec![];
+        let mut key = None;
+        loop {
+            let (page, pagination) = collection_page(&module, page(key, None, 4));
+            assert!(page.len() <= 4);
+            ids.extend(page);
+            match pagination.next_key {
+                Some(next_key) => key = Some(next_key),
+                None => break,
+            }
+        }
+        assert_eq!(
+            ids,
+            ["1", "10", "11", "2", "3", "4", "5", "6", "7", "8", "9"]
+        );
+    }
+
+    #[test]
+    fn page_boundaries() {
+        let module = eleven_tokens();
+
+        // A page ending on the last item has no next key.
+        let (ids, pagination) = collection_page(&module, page(None, Some(7), 4));
+        assert_eq!(ids, ["6", "7", "8", "9"]);
+        assert_eq!(pagination.next_key, None);
+
+        // A page ending one item short of the end points at that item.
+        let (ids, pagination) = collection_page(&module, page(None, Some(6), 4));
+        assert_eq!(ids, ["5", "6", "7", "8"]);
+        assert_eq!(pagination.next_key, Some(Binary::from(b"9").to_base64()));
+
+        // An offset past the end returns an empty page.
+        let (ids, pagination) = collection_page(&module, page(None, Some(20), 4));
+        assert!(ids.is_empty());
+        assert_eq!(pagination.next_key, None);
+
+        // No limit falls back to the default page size.
+        let (ids, _) = collection_page(&module, None);
+        assert_eq!(ids.len(), 11);
+    }
+
+    #[test]
+    fn key_starts_at_the_first_item_at_or_after_it() {
+        let module = eleven_tokens();
+        let key = |key: &[u8]| Some(Binary::from(key).to_base64());
+
+        let (ids, _) = collection_page(&module, page(key(b"3"), None, 2));
+        assert_eq!(ids, ["3", "4"]);
+        // The key of a missing item starts at the next one.
+        let (ids, _) = collection_page(&module, page(key(b"10\0"), None, 2));
+        assert_eq!(ids, ["11", "2"]);
+        let (ids, pagination) = collection_page(&module, page(key(b"99"), None, 2));
+        assert!(ids.is_empty());
+        assert_eq!(pagination.next_key, None);
+    }
+
+    #[test]
+    fn reverse_pages() {
+        let module = eleven_tokens();
+        let reverse = |key: Option<String>| {
+            Some(PaginationRequest {
+                key,
+                offset: None,
+                limit: Some(3),
+                count_total: None,
+                reverse: Some(true),
+            })
+        };
+
+        let (ids, pagination) = collection_page(&module, reverse(None));
+        assert_eq!(ids, ["9", "8", "7"]);
+        let (ids, _) = collection_page(&module, reverse(pagination.next_key));
+        assert_eq!(ids, ["6", "5", "4"]);
+        // Iterating backwards, a key starts at the first item at or before it.
+        let (ids, _) = collection_page(&module, reverse(Some(Binary::from(b"2\0").to_base64())));
+        assert_eq!(ids, ["2", "11", "10"]);
+    }
+
+    #[test]
+    fn count_total_only_with_offset() {
+        let module = eleven_tokens();
+        let request = |key: Option<String>, offset: Option<u64>| {
+            Some(PaginationRequest {
+                key,
+                offset,
+                limit: Some(2),
+                count_total: Some(true),
+                reverse: None,
+            })
+        };
+
+        let (_, pagination) = collection_page(&module, request(None, Some(2)));
+        assert_eq!(pagination.total, Some(11));
+        let (_, pagination) = collection_page(&module, request(pagination.next_key, None));
+        assert_eq!(pagination.total, None);
+    }
+
+    #[test]
+    fn owner_pages_are_keyed_by_token_id_within_a_denom() {
+        let module = eleven_tokens();
+        let owner_page = |denom_id: Option<&str>, key: &[u8]| {
+            let res: OwnerCollectionResponse = query(
+                &module,
+                CudosQuery::QueryOwner {
+                    denom_id: denom_id.map(str::to_string),
+                    address: OWNER.to_string(),
+                    pagination: page(Some(Binary::from(key).to_base64()), None, 1),
+                },
+            );
+            (
+                res.owner.id_collections[0].token_ids.clone(),
+                res.pagination.unwrap().next_key,
+            )
+        };
+
+        assert_eq!(
+            owner_page(Some("kittens"), b"2"),
+            (vec!["2".to_string()], Some(Binary::from(b"3").to_base64()))
+        );
+        assert_eq!(
+            owner_page(None, b"kittens/2"),
+            (
+                vec!["2".to_string()],
+                Some(Binary::from(b"kittens/3").to_base64())
+            )
+        );
+    }
+
+    #[test]
+    fn key_and_offset_are_exclusive() {
+        let module = eleven_tokens();
+        let err = module
+            .query(&CudosQuery::QueryDenoms {
+                pagination: page(Some(Binary::from(b"1").to_base64()), Some(1), 2),
+            })
+            .unwrap_err();
+        assert!(err.to_string().contains("either offset or key"));
+    }
+
+    #[test]
+    fn query_denoms_pages() {
+        let mut module = setup();
+        module
+            .issue_denom("puppies", "Puppies", "PUP", None, CREATOR)
+            .unwrap();
+        module
+            .issue_denom("birds", "Birds", "BRD", None, CREATOR)
+            .unwrap();
+
+        let res: DenomsResponse = query(
+            &module,
+            CudosQuery::QueryDenoms {
+                pagination: page(None, None, 2),
+            },
+        );
+        let ids: Vec<_> = res.denoms.unwrap().into_iter().map(|d| d.id).collect();
+        assert_eq!(ids, ["birds", "kittens"]);
+        let res: DenomsResponse = query(
+            &module,
+            CudosQuery::QueryDenoms {
+                pagination: page(res.pagination.unwrap().next_key, None, 2),
+            },
+        );
+        let ids: Vec<_> = res.denoms.unwrap().into_iter().map(|d| d.id).collect();
+        assert_eq!(ids, ["puppies"]);
+        assert_eq!(res.pagination.unwrap().next_key, None);
+    }
+}
@@ -0,0 +1,65 @@
+use cosmwasm_std::testing::{MockQuerier, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{
+    from_slice, Coin, ContractResult, Querier, QuerierResult, QueryRequest, SystemError,
+    SystemResult,
+};
+
+use crate::query::CudosQuery;
+use crate::testing::nft::{redact_module_error, MockNftModule};
+
+/// Querier that serves `CudosQuery` requests from a `MockNftModule` and forwards
+/// everything else to the standard cosmwasm `MockQuerier`.
+pub struct CudosMockQuerier {
+    base: MockQuerier<CudosQuery>,
+    pub nft: MockNftModule,
+}
+
+impl CudosMockQuerier {
+    pub fn new(balances: &[(&str, &[Coin])]) -> Self {
+        CudosMockQuerier {
+            base: MockQuerier::new(balances),
+            nft: MockNftModule::new(),
+        }
+    }
+
+    // set a new balance for the given address and return the old balance
+    pub fn update_balance(
+        &mut self,
+        addr: impl Into<String>,
+        balance: Vec<Coin>,
+    ) -> Option<Vec<Coin>> {
+        self.base.update_balance(addr, balance)
+    }
+
+    pub fn handle_query(&self, request: &QueryRequest<CudosQuery>) -> QuerierResult {
+        match request {
+            // Like wasmd, failed queries only return the codespace and code of their error.
+            QueryRequest::Custom(cudos_query) => match self.nft.query(cudos_query) {
+                Ok(response) => SystemResult::Ok(ContractResult::Ok(response)),
+                Err(err) => SystemResult::Ok(ContractResult::Err(redact_module_error(&err))),
+            },
+            _ => self.base.handle_query(request),
+        }
+    }
+}
+
+impl Default for CudosMockQuerier {
+    fn default() -> Self {
+        CudosMockQuerier::new(&[(MOCK_CONTRACT_ADDR, &[])])
+    }
+}
+
+impl Querier for CudosMockQuerier {
+    fn raw_query(&self, bin_request: &[u8]) -> QuerierResult {
+        let request: QueryRequest<CudosQuery> = match from_slice(bin_request) {
+            Ok(v) => v,
+            Err(e) => {
+                return SystemResult::Err(SystemError::InvalidRequest {
+                    error: format!("Parsing query request: {}", e),
+                    request: bin_request.into(),
+                })
+            }
+        };
+        self.handle_query(&request)
+    }
+}
@@ -0,0 +1,267 @@
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+use cosmwasm_std::from_slice;
+use serde::de::DeserializeOwned;
+
+use crate::error::CudosError;
+use crate::querier::CudosQuerier;
+use crate::query::{PaginationRequest, NFT};
+
+impl NFT {
+    /// Deserializes the JSON `data` of the NFT. Returns `None` when the NFT has no data.
+    pub fn parse_data<T: DeserializeOwned>(&self) -> Result<Option<T>, CudosError> {
+        match self.data.as_deref() {
+            None | Some("") => Ok(None),
+            Some(data) => {
+                from_slice(data.as_bytes())
+                    .map(Some)
+                    .map_err(|err| CudosError::InvalidNftData {
+                        token_id: self.id.clone(),
+                        reason: err.to_string(),
+                    })
+            }
+        }
+    }
+}
+
+/// An NFT with its `data` deserialized into `T`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TypedNft<T> {
+    pub id: String,
+    pub name: Option<String>,
+    pub uri: Option<String>,
+    pub data: Option<T>,
+    pub owner: String,
+    pub approved_addresses: Option<Vec<String>>,
+}
+
+impl<T: DeserializeOwned> TryFrom<NFT> for TypedNft<T> {
+    type Error = CudosError;
+
+    fn try_from(nft: NFT) -> Result<Self, Self::Error> {
+        let data = nft.parse_data()?;
+        Ok(TypedNft {
+            id: nft.id,
+            name: nft.name,
+            uri: nft.uri,
+            data,
+            owner: nft.owner,
+            approved_addresses: nft.approved_addresses,
+        })
+    }
+}
+
+/// Queries the NFTs of a single denom whose `data` is a JSON encoded `T`.
+pub struct TypedCollection<'a, T> {
+    querier: &'a CudosQuerier<'a>,
+    denom_id: String,
+    data: PhantomData<T>,
+}
+
+impl<'a, T: DeserializeOwned> TypedCollection<'a, T> {
+    pub fn new(querier: &'a CudosQuerier<'a>, denom_id: impl Into<String>) -> Self {
+        TypedCollection {
+            querier,
+            denom_id: denom_id.into(),
+            data: PhantomData,
+        }
+    }
+
+    pub fn denom_id(&self) -> &str {
+        &self.denom_id
+    }
+
+    pub fn token(&self, token_id: impl Into<String>) -> Result<TypedNft<T>, CudosError> {
+        let res = self
+            .querier
+            .try_query_token(self.denom_id.clone(), token_id.into())?;
+        TypedNft::try_from(res.nft)
+    }
+
+    /// Returns `None` when the token does not exist.
+    pub fn token_opt(
+        &self,
+        token_id: impl Into<String>,
+    ) -> Result<Option<TypedNft<T>>, CudosError> {
+        match self.token(token_id) {
+            Err(err) if err.is_not_found() => Ok(None),
+            res => res.map(Some),
+        }
+    }
+
+    /// Returns a page of the collection, failing on the first NFT with malformed data.
+    pub fn nfts(
+        &self,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<(Vec<TypedNft<T>>, Option<String>), CudosError> {
+        let res = self
+            .querier
+            .try_query_collection(self.denom_id.clone(), pagination)?;
+        let nfts = res
+            .collection
+            .and_then(|collection| collection.nfts)
+            .unwrap_or_default()
+            .into_iter()
+            .map(TypedNft::try_from)
+            .collect::<Result<_, _>>()?;
+        Ok((nfts, res.pagination.and_then(|page| page.next_key)))
+    }
+
+    /// Iterates over the whole collection, fetching `page_size` NFTs per query.
+    pub fn iter(
+        &self,
+        page_size: u64,
+    ) -> impl Iterator<Item = Result<TypedNft<T>, CudosError>> + '_ {
+        self.querier
+            .collection_nfts_iter(self.denom_id.clone(), page_size)
+            .map(|nft| TypedNft::try_from(nft?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde::Deserialize;
+
+    use crate::testing::mock_cudos_dependencies;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Meta {
+        level: u32,
+    }
+
+    fn nft(data: Option<&str>) -> NFT {
+        NFT {
+            id: "1".to_string(),
+            name: Some("first".to_string()),
+            uri: Some("ipfs://first".to_string()),
+            data: data.map(String::from),
+            owner: "owner".to_string(),
+            approved_addresses: Some(vec!["operator".to_string()]),
+        }
+    }
+
+    fn assert_invalid_data<T: std::fmt::Debug>(result: Result<T, CudosError>, token: &str) {
+        match result.unwrap_err() {
+            CudosError::InvalidNftData { token_id, .. } => assert_eq!(token_id, token),
+            err => panic!("unexpected error {:?}", err),
+        }
+    }
+
+    #[test]
+    fn parses_data() {
+        assert_eq!(nft(None).parse_data::<Meta>().unwrap(), None);
+        assert_eq!(nft(Some("")).parse_data::<Meta>().unwrap(), None);
+        assert_eq!(
+            nft(Some(r#"{"level":3}"#)).parse_data::<Meta>().unwrap(),
+            Some(Meta { level: 3 })
+        );
+        assert_invalid_data(nft(Some("not json")).parse_data::<Meta>(), "1");
+        assert_invalid_data(nft(Some(r#"{"level":"high"}"#)).parse_data::<Meta>(), "1");
+    }
+
+    #[test]
+    fn typed_nft_keeps_the_other_fields() {
+        let typed = TypedNft::<Meta>::try_from(nft(Some(r#"{"level":3}"#))).unwrap();
+        assert_eq!(
+            typed,
+            TypedNft {
+                id: "1".to_string(),
+                name: Some("first".to_string()),
+                uri: Some("ipfs://first".to_string()),
+                data: Some(Meta { level: 3 }),
+                owner: "owner".to_string(),
+                approved_addresses: Some(vec!["operator".to_string()]),
+            }
+        );
+
+        let typed = TypedNft::<Meta>::try_from(nft(None)).unwrap();
+        assert_eq!(typed.data, None);
+        assert_invalid_data(TypedNft::<Meta>::try_from(nft(Some("[]"))), "1");
+    }
+
+    #[test]
+    fn collection_decodes_the_tokens() {
+        let mut deps = mock_cudos_dependencies();
+        let module = &mut deps.querier.nft;
+        module
+            .issue_denom("kittens", "Kittens", "KIT", None, "creator")
+            .unwrap();
+        for level in 1..=3 {
+            let data = format!(r#"{{"level":{}}}"#, level);
+            module
+                .mint_nft("kittens", "kitten", None, Some(data), "owner")
+                .unwrap();
+        }
+        module
+            .mint_nft("kittens", "plain", None, None, "owner")
+            .unwrap();
+        let deps = deps.as_ref();
+        let querier = CudosQuerier::new(&deps.querier);
+        let collection = TypedCollection::<Meta>::new(&querier, "kittens");
+        assert_eq!(collection.denom_id(), "kittens");
+
+        let token = collection.token("2").unwrap();
+        assert_eq!(token.data, Some(Meta { level: 2 }));
+        assert_eq!(collection.token_opt("4").unwrap().unwrap().data, None);
+        assert_eq!(collection.token_opt("5").unwrap(), None);
+        assert!(collection.token("5").unwrap_err().is_not_found());
+
+        let (page, next_key) = collection
+            .nfts(Some(PaginationRequest {
+                key: None,
+                offset: None,
+                limit: Some(2),
+                count_total: None,
+                reverse: None,
+            }))
+            .unwrap();
+        let levels: Vec<_> = page
+            .iter()
+            .map(|nft| nft.data.as_ref().unwrap().level)
+            .collect();
+        assert_eq!(levels, [1, 2]);
+        assert!(next_key.is_some());
+
+        let levels = collection
+            .iter(3)
+            .map(|nft| nft.map(|nft| nft.data.map(|meta| meta.level)))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(levels, [Some(1), Some(2), Some(3), None]);
+    }
+
+    #[test]
+    fn collection_fails_on_malformed_data() {
+        let mut deps = mock_cudos_dependencies();
+        let module = &mut deps.querier.nft;
+        module
+            .issue_denom("kittens", "Kittens", "KIT", None, "creator")
+            .unwrap();
+        module
+            .mint_nft(
+                "kittens",
+                "good",
+                None,
+                Some(r#"{"level":1}"#.to_string()),
+                "owner",
+            )
+            .unwrap();
+        module
+            .mint_nft("kittens", "bad", None, Some("oops".to_string()), "owner")
+            .unwrap();
+        let deps = deps.as_ref();
+        let querier = CudosQuerier::new(&deps.querier);
+        let collection = TypedCollection::<Meta>::new(&querier, "kittens");
+
+        assert_invalid_data(collection.token("2"), "2");
+        assert_invalid_data(collection.token_opt("2"), "2");
+        assert_invalid_data(collection.nfts(None), "2");
+
+        let mut iter = collection.iter(1);
+        assert!(iter.next().unwrap().is_ok());
+        assert_invalid_data(iter.next().unwrap(), "2");
+    }
+}
@@ -0,0 +1,505 @@
+use cosmwasm_std::{Addr, Api, StdError};
+use thiserror::Error;
+
+use crate::msg::CudosMsg;
+use crate::query::CudosQuery;
+
+// Limits enforced by the x/nft module of the Cudos node.
+pub const MIN_DENOM_LEN: usize = 3;
+pub const MAX_DENOM_LEN: usize = 64;
+pub const MAX_URI_LEN: usize = 256;
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ValidationError {
+    #[error("invalid address {address:?} in field `{field}`: {reason}")]
+    InvalidAddress {
+        field: &'static str,
+        address: String,
+        reason: String,
+    },
+    #[error("invalid denom id {denom_id:?}: {reason}")]
+    InvalidDenomId { denom_id: String, reason: String },
+    #[error("invalid token id {token_id:?}: {reason}")]
+    InvalidTokenId { token_id: String, reason: String },
+    #[error("invalid denom name {name:?}: {reason}")]
+    InvalidDenomName { name: String, reason: String },
+    #[error("invalid denom symbol {symbol:?}: {reason}")]
+    InvalidSymbol { symbol: String, reason: String },
+    #[error("invalid uri {uri:?}: {reason}")]
+    InvalidUri { uri: String, reason: String },
+    #[error("invalid schema of denom {denom_id:?}: {reason}")]
+    InvalidSchema { denom_id: String, reason: String },
+    #[error("data does not match the schema of denom {denom_id:?}: {reason}")]
+    SchemaMismatch { denom_id: String, reason: String },
+}
+
+impl From<ValidationError> for StdError {
+    fn from(err: ValidationError) -> Self {
+        StdError::generic_err(err.to_string())
+    }
+}
+
+/// Runs `api.addr_validate` on `address`, naming `field` in the error.
+pub fn validate_addr(
+    api: &dyn Api,
+    field: &'static str,
+    address: &str,
+) -> Result<Addr, ValidationError> {
+    api.addr_validate(address)
+        .map_err(|err| ValidationError::InvalidAddress {
+            field,
+            address: address.to_string(),
+            reason: err.to_string(),
+        })
+}
+
+/// Denom ids must be 3 to 64 lowercase alphanumeric characters, starting with a letter.
+pub fn validate_denom_id(denom_id: &str) -> Result<(), ValidationError> {
+    let invalid = |reason: String| ValidationError::InvalidDenomId {
+        denom_id: denom_id.to_string(),
+        reason,
+    };
+
+    if denom_id.len() < MIN_DENOM_LEN || denom_id.len() > MAX_DENOM_LEN {
+        return Err(invalid(format!(
+            "length must be between {} and {}",
+            MIN_DENOM_LEN, MAX_DENOM_LEN
+        )));
+    }
+    if !denom_id.starts_with(|c: char| c.is_ascii_lowercase())
+        || !denom_id
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+    {
+        return Err(invalid(
+            "only lowercase alphanumeric characters are accepted and it must begin with a letter"
+                .to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Token ids are assigned by the module as sequential unsigned integers.
+pub fn validate_token_id(token_id: &str) -> Result<(), ValidationError> {
+    if token_id.is_empty() || !token_id.chars().all(|c| c.is_ascii_digit()) {
+        return Err(ValidationError::InvalidTokenId {
+            token_id: token_id.to_string(),
+            reason: "must be an unsigned integer".to_string(),
+        });
+    }
+    if token_id.parse::<u64>().is_err() {
+        return Err(ValidationError::InvalidTokenId {
+            token_id: token_id.to_string(),
+            reason: "must fit in 64 bits".to_string(),
+        });
+    }
+    Ok(())
+}
+
+pub fn validate_denom_name(name: &str) -> Result<(), ValidationError> {
+    if name.trim().is_empty() {
+        return Err(ValidationError::InvalidDenomName {
+            name: name.to_string(),
+            reason: "can not be blank".to_string(),
+        });
+    }
+    Ok(())
+}
+
+pub fn validate_symbol(symbol: &str) -> Result<(), ValidationError> {
+    if symbol.trim().is_empty() {
+        return Err(ValidationError::InvalidSymbol {
+            symbol: symbol.to_string(),
+            reason: "can not be blank".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Ethereum addresses are 20 bytes, written as 40 hex digits after `0x`.
+pub fn validate_eth_address(field: &'static str, address: &str) -> Result<(), ValidationError> {
+    let digits = address.strip_prefix("0x").unwrap_or_default();
+    if digits.len() != 40 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(ValidationError::InvalidAddress {
+            field,
+            address: address.to_string(),
+            reason: "must be 0x followed by 40 hexadecimal digits".to_string(),
+        });
+    }
+    Ok(())
+}
+
+pub fn validate_uri(uri: &str) -> Result<(), ValidationError> {
+    if uri.len() > MAX_URI_LEN {
+        return Err(ValidationError::InvalidUri {
+            uri: uri.to_string(),
+            reason: format!("length must not exceed {}", MAX_URI_LEN),
+        });
+    }
+    Ok(())
+}
+
+impl CudosMsg {
+    /// Checks the ids, names, symbols and uris of the message against the rules of the x/nft
+    /// module, and the Ethereum addresses of the bridge messages, so invalid messages are
+    /// rejected before they leave the contract.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            CudosMsg::IssueDenomMsg {
+                id,
+                name,
+                symbol,
+                uri,
+                ..
+            } => {
+                validate_denom_id(id)?;
+                validate_denom_name(name)?;
+                validate_symbol(symbol)?;
+                uri.as_deref().map_or(Ok(()), validate_uri)
+            }
+            CudosMsg::MintNftMsg { denom_id, uri, .. }
+            | CudosMsg::MarketplaceMintNftMsg { denom_id, uri, .. } => {
+                validate_denom_id(denom_id)?;
+                uri.as_deref().map_or(Ok(()), validate_uri)
+            }
+            CudosMsg::EditNftMsg {
+                denom_id,
+                token_id,
+                uri,
+                ..
+            } => {
+                validate_denom_id(denom_id)?;
+                validate_token_id(token_id)?;
+                uri.as_deref().map_or(Ok(()), validate_uri)
+            }
+            CudosMsg::TransferNftMsg {
+                denom_id, token_id, ..
+            }
+            | CudosMsg::BurnNftMsg {
+                denom_id, token_id, ..
+            }
+            | CudosMsg::ApproveNftMsg {
+                denom_id, token_id, ..
+            }
+            | CudosMsg::RevokeApprovalMsg {
+                denom_id, token_id, ..
+            }
+            | CudosMsg::PublishNftMsg {
+                denom_id, token_id, ..
+            } => {
+                validate_denom_id(denom_id)?;
+                validate_token_id(token_id)
+            }
+            CudosMsg::TransferDenomMsg { denom_id, .. }
+            | CudosMsg::PublishCollectionMsg { denom_id, .. } => validate_denom_id(denom_id),
+            CudosMsg::ApproveAllMsg { .. }
+            | CudosMsg::UnpublishCollectionMsg { .. }
+            | CudosMsg::UnpublishNftMsg { .. }
+            | CudosMsg::BuyNftMsg { .. }
+            | CudosMsg::UpdatePriceMsg { .. }
+            | CudosMsg::CreateAddressMsg { .. }
+            | CudosMsg::UpdateAddressMsg { .. }
+            | CudosMsg::DeleteAddressMsg { .. }
+            | CudosMsg::CancelSendToEthMsg { .. } => Ok(()),
+            CudosMsg::SendToEthMsg { eth_dest, .. } => validate_eth_address("eth_dest", eth_dest),
+        }
+    }
+
+    /// Returns every address carried by the message together with the name of its field.
+    pub fn addresses(&self) -> Vec<(&'static str, &str)> {
+        let (sender, contract_address_signer, mut addresses) = match self {
+            CudosMsg::IssueDenomMsg {
+                minter,
+                sender,
+                contract_address_signer,
+                ..
+            } => (
+                sender,
+                contract_address_signer,
+                minter
+                    .iter()
+                    .map(|minter| ("minter", minter.as_str()))
+                    .collect(),
+            ),
+            CudosMsg::EditNftMsg {
+                sender,
+                contract_address_signer,
+                ..
+            }
+            | CudosMsg::BurnNftMsg {
+                sender,
+                contract_address_signer,
+                ..
+            }
+            | CudosMsg::UnpublishCollectionMsg {
+                sender,
+                contract_address_signer,
+                ..
+            }
+            | CudosMsg::PublishNftMsg {
+                sender,
+                contract_address_signer,
+                ..
+            }
+            | CudosMsg::UnpublishNftMsg {
+                sender,
+                contract_address_signer,
+                ..
+            }
+            | CudosMsg::UpdatePriceMsg {
+                sender,
+                contract_address_signer,
+                ..
+            }
+            | CudosMsg::CreateAddressMsg {
+                sender,
+                contract_address_signer,
+                ..
+            }
+            | CudosMsg::UpdateAddressMsg {
+                sender,
+                contract_address_signer,
+                ..
+            }
+            | CudosMsg::DeleteAddressMsg {
+                sender,
+                contract_address_signer,
+                ..
+            }
+            | CudosMsg::SendToEthMsg {
+                sender,
+                contract_address_signer,
+                ..
+            }
+            | CudosMsg::CancelSendToEthMsg {
+                sender,
+                contract_address_signer,
+                ..
+            } => (sender, contract_address_signer, vec![]),
+            CudosMsg::MintNftMsg {
+                recipient,
+                sender,
+                contract_address_signer,
+                ..
+            }
+            | CudosMsg::BuyNftMsg {
+                recipient,
+                sender,
+                contract_address_signer,
+                ..
+            }
+            | CudosMsg::MarketplaceMintNftMsg {
+                recipient,
+                sender,
+                contract_address_signer,
+                ..
+            } => (
+                sender,
+                contract_address_signer,
+                vec![("recipient", recipient.as_str())],
+            ),
+            CudosMsg::TransferNftMsg {
+                from,
+                to,
+                sender,
+                contract_address_signer,
+                ..
+            } => (
+                sender,
+                contract_address_signer,
+                vec![("from", from.as_str()), ("to", to.as_str())],
+            ),
+            CudosMsg::TransferDenomMsg {
+                to,
+                sender,
+                contract_address_signer,
+                ..
+            } => (sender, contract_address_signer, vec![("to", to.as_str())]),
+            CudosMsg::ApproveNftMsg {
+                approved_address,
+                sender,
+                contract_address_signer,
+                ..
+            } => (
+                sender,
+                contract_address_signer,
+                vec![("approved_address", approved_address.as_str())],
+            ),
+            CudosMsg::ApproveAllMsg {
+                approved_operator,
+                sender,
+                contract_address_signer,
+                ..
+            } => (
+                sender,
+                contract_address_signer,
+                vec![("approved_operator", approved_operator.as_str())],
+            ),
+            CudosMsg::RevokeApprovalMsg {
+                address_to_revoke,
+                sender,
+                contract_address_signer,
+                ..
+            } => (
+                sender,
+                contract_address_signer,
+                vec![("address_to_revoke", address_to_revoke.as_str())],
+            ),
+            CudosMsg::PublishCollectionMsg {
+                mint_royalties,
+                resale_royalties,
+                sender,
+                contract_address_signer,
+                ..
+            } => (
+                sender,
+                contract_address_signer,
+                mint_royalties
+                    .iter()
+                    .map(|royalty| ("mint_royalties", royalty.address.as_str()))
+                    .chain(
+                        resale_royalties
+                            .iter()
+                            .map(|royalty| ("resale_royalties", royalty.address.as_str())),
+                    )
+                    .collect(),
+            ),
+        };
+
+        addresses.push(("sender", sender.as_str()));
+        addresses.push(("contract_address_signer", contract_address_signer.as_str()));
+        addresses
+    }
+
+    /// Validates every address of the message with `api.addr_validate`, failing on the first
+    /// invalid one.
+    pub fn validate_addresses(&self, api: &dyn Api) -> Result<(), ValidationError> {
+        for (field, address) in self.addresses() {
+            validate_addr(api, field, address)?;
+        }
+        Ok(())
+    }
+}
+
+impl CudosQuery {
+    /// Returns every address carried by the query together with the name of its field.
+    pub fn addresses(&self) -> Vec<(&'static str, &str)> {
+        match self {
+            CudosQuery::QueryOwner { address, .. } => vec![("address", address.as_str())],
+            CudosQuery::QueryApprovedForAll {
+                owner_address,
+                operator_address,
+            } => vec![
+                ("owner_address", owner_address.as_str()),
+                ("operator_address", operator_address.as_str()),
+            ],
+            CudosQuery::QueryAddress { creator, .. } => vec![("creator", creator.as_str())],
+            CudosQuery::QueryDenomById { .. }
+            | CudosQuery::QueryDenomByName { .. }
+            | CudosQuery::QueryDenomBySymbol { .. }
+            | CudosQuery::QueryDenoms { .. }
+            | CudosQuery::QueryCollection { .. }
+            | CudosQuery::QuerySupply { .. }
+            | CudosQuery::QueryToken { .. }
+            | CudosQuery::QueryApprovals { .. }
+            | CudosQuery::QueryMarketplaceCollection { .. }
+            | CudosQuery::QueryMarketplaceCollectionByDenomId { .. }
+            | CudosQuery::QueryMarketplaceCollections { .. }
+            | CudosQuery::QueryListing { .. }
+            | CudosQuery::QueryListings { .. }
+            | CudosQuery::QueryPendingBatches {}
+            | CudosQuery::QueryBatchFees {}
+            | CudosQuery::QueryLastObservedEthNonce {}
+            | CudosQuery::QueryErc20ToDenom { .. }
+            | CudosQuery::QueryDenomToErc20 { .. }
+            | CudosQuery::MintParams {}
+            | CudosQuery::AnnualProvisions {} => vec![],
+        }
+    }
+
+    /// Validates every address of the query with `api.addr_validate`, failing on the first
+    /// invalid one.
+    pub fn validate_addresses(&self, api: &dyn Api) -> Result<(), ValidationError> {
+        for (field, address) in self.addresses() {
+            validate_addr(api, field, address)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factory::CudosMsgFactory;
+    use cosmwasm_std::testing::{mock_env, mock_info, MockApi};
+    use cosmwasm_std::CosmosMsg;
+
+    fn invalid_field(err: ValidationError) -> &'static str {
+        match err {
+            ValidationError::InvalidAddress { field, .. } => field,
+            err => panic!("expected an invalid address, got {:?}", err),
+        }
+    }
+
+    #[test]
+    fn validates_the_addresses_of_msgs() {
+        let api = MockApi::default();
+        let factory = CudosMsgFactory::new(&mock_env(), &mock_info("owner", &[]));
+        let msg = |to: &str| match factory.transfer_nft::<CudosMsg>("kittens", "1", "owner", to) {
+            CosmosMsg::Custom(msg) => msg,
+            _ => unreachable!(),
+        };
+
+        assert_eq!(msg("recipient").validate_addresses(&api), Ok(()));
+        let err = msg("RECIPIENT").validate_addresses(&api).unwrap_err();
+        assert_eq!(invalid_field(err), "to");
+    }
+
+    #[test]
+    fn validates_the_addresses_of_queries() {
+        let api = MockApi::default();
+        let query = CudosQuery::QueryApprovedForAll {
+            owner_address: "owner".to_string(),
+            operator_address: "x".to_string(),
+        };
+        assert_eq!(
+            query.addresses(),
+            vec![("owner_address", "owner"), ("operator_address", "x")]
+        );
+        let err = query.validate_addresses(&api).unwrap_err();
+        assert_eq!(invalid_field(err), "operator_address");
+
+        let query = CudosQuery::QueryOwner {
+            denom_id: None,
+            address: "owner".to_string(),
+            pagination: None,
+        };
+        assert_eq!(query.validate_addresses(&api), Ok(()));
+        let query = CudosQuery::QueryAddress {
+            creator: "Creator".to_string(),
+            network: "ETH".to_string(),
+            label: "payout".to_string(),
+        };
+        assert_eq!(
+            invalid_field(query.validate_addresses(&api).unwrap_err()),
+            "creator"
+        );
+        assert!(CudosQuery::MintParams {}.addresses().is_empty());
+    }
+
+    #[test]
+    fn typed_factory_methods_match_the_untyped_ones() {
+        let api = MockApi::default();
+        let factory = CudosMsgFactory::new(&mock_env(), &mock_info("owner", &[]));
+        let owner = validate_addr(&api, "owner", "owner").unwrap();
+        let recipient = validate_addr(&api, "recipient", "recipient").unwrap();
+
+        let msg: CosmosMsg<CudosMsg> = factory.transfer_nft_to("kittens", "1", &owner, &recipient);
+        assert_eq!(
+            msg,
+            factory.transfer_nft("kittens", "1", "owner", "recipient")
+        );
+        let msg: CosmosMsg<CudosMsg> = factory.approve_all_for(&recipient, true);
+        assert_eq!(msg, factory.approve_all("recipient", true));
+    }
+}